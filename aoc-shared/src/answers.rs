@@ -0,0 +1,335 @@
+//! The accepted answers for each day's puzzle input, to check solutions against
+use crate::parse::ParseError;
+use crate::solution::Report;
+use std::fmt::{self, Display};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// The answers file, next to `input.txt` in a day's `src` directory
+pub fn answers_path(day_dir: impl AsRef<Path>) -> PathBuf {
+    day_dir.as_ref().join("src").join("answers.txt")
+}
+
+/// The accepted answers for one day, if they have been recorded.
+///
+/// The file has a `[partN]` header before each answer, so that answers can
+/// span several lines:
+///
+/// ```text
+/// [part1]
+/// 16020
+/// [part2]
+/// ####..##..
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    /// Load the answers for a day, which are all missing if there's no file yet
+    pub fn load(day_dir: impl AsRef<Path>) -> Result<Self, AnswersError> {
+        let path = answers_path(day_dir);
+
+        let text = match std::fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Answers::default()),
+            Err(source) => return Err(AnswersError::Io { path, source }),
+        };
+
+        text.parse()
+            .map_err(|source| AnswersError::Parse { path, source })
+    }
+
+    pub fn save(&self, day_dir: impl AsRef<Path>) -> Result<(), AnswersError> {
+        let path = answers_path(day_dir);
+
+        std::fs::write(&path, self.to_string()).map_err(|source| AnswersError::Io { path, source })
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Panics if `part` isn't 1 or 2
+    pub fn set(&mut self, part: u8, answer: impl Into<String>) {
+        match part {
+            1 => self.part1 = Some(answer.into()),
+            2 => self.part2 = Some(answer.into()),
+            _ => panic!("No part {part} of a puzzle"),
+        }
+    }
+
+    /// Record every answer in the report
+    pub fn record(&mut self, report: &Report) {
+        for answer in &report.answers {
+            self.set(answer.part, answer.answer.clone());
+        }
+    }
+
+    /// Compare every answer in the report with the accepted answer
+    pub fn check(&self, report: &Report) -> Vec<Check> {
+        report
+            .answers
+            .iter()
+            .map(|answer| {
+                let status = match self.get(answer.part) {
+                    None => Status::Unrecorded,
+                    Some(expected) if expected == answer.answer => Status::Correct,
+                    Some(expected) => Status::Wrong {
+                        expected: expected.to_string(),
+                    },
+                };
+
+                Check {
+                    day: report.day,
+                    part: answer.part,
+                    answer: answer.answer.clone(),
+                    status,
+                }
+            })
+            .collect()
+    }
+}
+
+impl FromStr for Answers {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Answers::default();
+        let mut current: Option<(u8, Vec<&str>)> = None;
+
+        let mut finish = |current: Option<(u8, Vec<&str>)>| {
+            if let Some((part, lines)) = current {
+                answers.set(part, lines.join("\n").trim_end());
+            }
+        };
+
+        for (i, line) in s.lines().enumerate() {
+            let part = match line {
+                "[part1]" => 1,
+                "[part2]" => 2,
+                _ => {
+                    match &mut current {
+                        Some((_, lines)) => lines.push(line),
+                        None if line.trim().is_empty() => (),
+                        None => {
+                            return Err(ParseError::new("Expected a [part1] or [part2] header")
+                                .line(i + 1)
+                                .context(line))
+                        }
+                    }
+                    continue;
+                }
+            };
+
+            finish(current.take());
+            current = Some((part, Vec::new()));
+        }
+
+        finish(current);
+
+        Ok(answers)
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for part in [1, 2] {
+            if let Some(answer) = self.get(part) {
+                writeln!(f, "[part{part}]")?;
+                writeln!(f, "{answer}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io { path: PathBuf, source: io::Error },
+    Parse { path: PathBuf, source: ParseError },
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Io { path, source } => write!(f, "{}: {source}", path.display()),
+            AnswersError::Parse { path, source } => write!(f, "{}: {source}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+// ----------------------------------------------------------------------------
+
+/// How an answer compares with the accepted answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Correct,
+    Wrong {
+        expected: String,
+    },
+    /// There's no accepted answer to compare with
+    Unrecorded,
+}
+
+/// The result of checking one part of a day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub status: Status,
+}
+
+impl Check {
+    pub fn is_wrong(&self) -> bool {
+        matches!(self.status, Status::Wrong { .. })
+    }
+}
+
+/// Formats checks as a table, one row per answer, with the expected answer
+/// on the row after any wrong one
+#[derive(Debug, Clone, Default)]
+pub struct CheckTable(pub Vec<Check>);
+
+impl Display for CheckTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn row(f: &mut fmt::Formatter<'_>, cells: [&str; 3], answer: &str) -> fmt::Result {
+            let [day, part, status] = cells;
+            let mut lines = answer.lines();
+            let first = lines.next().unwrap_or("");
+
+            writeln!(f, "{day:>3}  {part:>4}  {status:10}  {first}")?;
+
+            for line in lines {
+                writeln!(f, "{:23}{line}", "")?;
+            }
+
+            Ok(())
+        }
+
+        writeln!(f, "{:>3}  {:>4}  {:10}  Answer", "Day", "Part", "Status")?;
+
+        for check in &self.0 {
+            let status = match check.status {
+                Status::Correct => "ok",
+                Status::Wrong { .. } => "WRONG",
+                Status::Unrecorded => "unrecorded",
+            };
+            let (day, part) = (check.day.to_string(), check.part.to_string());
+
+            row(f, [&day, &part, status], &check.answer)?;
+
+            if let Status::Wrong { expected } = &check.status {
+                row(f, ["", "", "expected"], expected)?;
+            }
+        }
+
+        let wrong = self.0.iter().filter(|check| check.is_wrong()).count();
+        let unrecorded = self
+            .0
+            .iter()
+            .filter(|check| check.status == Status::Unrecorded)
+            .count();
+
+        writeln!(
+            f,
+            "{} correct, {wrong} wrong, {unrecorded} unrecorded",
+            self.0.len() - wrong - unrecorded
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Answer;
+    use std::time::Duration;
+
+    fn report(answers: &[&str]) -> Report {
+        Report {
+            day: 10,
+            title: "Test",
+            parse_time: Duration::ZERO,
+            answers: answers
+                .iter()
+                .enumerate()
+                .map(|(i, answer)| Answer {
+                    part: i as u8 + 1,
+                    answer: answer.to_string(),
+                    time: Duration::ZERO,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let answers = Answers {
+            part1: Some("42".to_string()),
+            part2: Some("#..#\n.##.".to_string()),
+        };
+        let text = "[part1]\n42\n[part2]\n#..#\n.##.\n";
+
+        assert_eq!(answers.to_string(), text);
+        assert_eq!(text.parse(), Ok(answers));
+
+        assert_eq!("".parse(), Ok(Answers::default()));
+        assert_eq!(
+            "\n[part2]\n7\n\n".parse(),
+            Ok(Answers {
+                part1: None,
+                part2: Some("7".to_string())
+            })
+        );
+
+        let err = "42\n".parse::<Answers>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Line 1: Expected a [part1] or [part2] header \"42\""
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let answers: Answers = "[part1]\n42\n".parse().unwrap();
+
+        let checks = answers.check(&report(&["42", "7"]));
+        assert_eq!(checks[0].status, Status::Correct);
+        assert_eq!(checks[1].status, Status::Unrecorded);
+
+        let checks = answers.check(&report(&["41"]));
+        assert!(checks[0].is_wrong());
+
+        assert_eq!(
+            CheckTable(checks).to_string(),
+            [
+                "Day  Part  Status      Answer",
+                " 10     1  WRONG       41",
+                "           expected    42",
+                "0 correct, 1 wrong, 0 unrecorded",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_record() {
+        let mut answers = Answers::default();
+        answers.record(&report(&["1", "2\n3"]));
+
+        assert_eq!(answers.get(1), Some("1"));
+        assert_eq!(answers.get(2), Some("2\n3"));
+    }
+}
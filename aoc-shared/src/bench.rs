@@ -0,0 +1,798 @@
+//! Timing the parse and both parts of each day separately, over repeated runs
+use crate::parse::ParseError;
+use crate::solution::Solution;
+use std::fmt::{self, Display, Write};
+use std::hint::black_box;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// How many times to run each stage of a solution
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchOptions {
+    /// Untimed runs before sampling starts
+    pub warmup: usize,
+    /// The most timed runs to take
+    pub samples: usize,
+    /// Stop taking samples of a stage once this much time has been spent on it,
+    /// so slow days don't hold up the whole run. At least one sample is always taken.
+    pub time_limit: Duration,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            warmup: 1,
+            samples: 10,
+            time_limit: Duration::from_secs(2),
+        }
+    }
+}
+
+/// Summary of the timed runs of one stage
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Panics if there are no times
+    pub fn from_times(mut times: Vec<Duration>) -> Self {
+        assert!(!times.is_empty(), "No times to summarise");
+        times.sort();
+
+        let samples = times.len();
+        let mid = samples / 2;
+        let median = if samples.is_multiple_of(2) {
+            (times[mid - 1] + times[mid]) / 2
+        } else {
+            times[mid]
+        };
+
+        Stats {
+            samples,
+            min: times[0],
+            median,
+            mean: times.iter().sum::<Duration>() / samples as u32,
+            max: times[samples - 1],
+        }
+    }
+
+    fn write_json(&self, out: &mut String) {
+        write!(
+            out,
+            r#"{{"samples": {}, "min_ns": {}, "median_ns": {}, "mean_ns": {}, "max_ns": {}}}"#,
+            self.samples,
+            self.min.as_nanos(),
+            self.median.as_nanos(),
+            self.mean.as_nanos(),
+            self.max.as_nanos()
+        )
+        .unwrap();
+    }
+}
+
+/// Write `s` as a quoted JSON string, escaping it as needed
+fn write_json_string(out: &mut String, s: &str) {
+    out.push('"');
+
+    for ch in s.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            ch if ch.is_control() => write!(out, "\\u{:04x}", ch as u32).unwrap(),
+            ch => out.push(ch),
+        }
+    }
+
+    out.push('"');
+}
+
+/// The timings of each stage of a day's solution
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchResult {
+    pub day: u8,
+    pub title: &'static str,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl BenchResult {
+    /// The median time to parse the input and solve both parts
+    pub fn total(&self) -> Duration {
+        self.parse.median + self.part1.median + self.part2.median
+    }
+}
+
+/// Run `f` for the warm-up, then time it until there are enough samples
+fn sample<T>(options: &BenchOptions, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..options.warmup {
+        black_box(f());
+    }
+
+    let mut times = Vec::new();
+    let mut spent = Duration::ZERO;
+
+    while times.is_empty() || (times.len() < options.samples && spent < options.time_limit) {
+        let start = Instant::now();
+        black_box(f());
+        let time = start.elapsed();
+
+        times.push(time);
+        spent += time;
+    }
+
+    Stats::from_times(times)
+}
+
+/// Time parsing the input, then each part, with the parsed input shared between the parts
+pub fn bench<S: Solution>(input: &str, options: &BenchOptions) -> Result<BenchResult, ParseError> {
+    let solution = S::parse(input)?;
+    let parse = sample(options, || S::parse(black_box(input)));
+
+    Ok(BenchResult {
+        day: S::DAY,
+        title: S::TITLE,
+        parse,
+        part1: sample(options, || solution.part1()),
+        part2: sample(options, || solution.part2()),
+    })
+}
+
+// ----------------------------------------------------------------------------
+
+/// Benchmark results for several days, to compare them side by side.
+///
+/// Displays as a table of median times, or can be written as JSON with
+/// [`BenchReport::to_json`], then read back as a [`Baseline`] to compare
+/// a later run against with [`BenchReport::compare`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchReport {
+    pub options: BenchOptions,
+    pub results: Vec<BenchResult>,
+}
+
+impl BenchReport {
+    /// The median times of this run beside those of an earlier one
+    pub fn compare<'a>(&'a self, baseline: &'a Baseline) -> Comparison<'a> {
+        Comparison {
+            report: self,
+            baseline,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+
+        writeln!(out, "{{").unwrap();
+        writeln!(
+            out,
+            r#"  "options": {{"warmup": {}, "samples": {}, "time_limit_ns": {}}},"#,
+            self.options.warmup,
+            self.options.samples,
+            self.options.time_limit.as_nanos()
+        )
+        .unwrap();
+        write!(out, r#"  "days": ["#).unwrap();
+
+        for (i, result) in self.results.iter().enumerate() {
+            let separator = if i == 0 { "" } else { "," };
+
+            write!(out, "{separator}\n    {{\"day\": {}, ", result.day).unwrap();
+            out.push_str("\"title\": ");
+            write_json_string(&mut out, result.title);
+            out.push_str(", ");
+
+            for (name, stats) in [
+                ("parse", &result.parse),
+                ("part1", &result.part1),
+                ("part2", &result.part2),
+            ] {
+                write!(out, "\"{name}\": ").unwrap();
+                stats.write_json(&mut out);
+                out.push_str(", ");
+            }
+
+            write!(out, "\"total_ns\": {}}}", result.total().as_nanos()).unwrap();
+        }
+
+        if !self.results.is_empty() {
+            out.push_str("\n  ");
+        }
+        out.push_str("]\n}\n");
+
+        out
+    }
+}
+
+impl Display for BenchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let title_width = self
+            .results
+            .iter()
+            .map(|result| result.title.len())
+            .max()
+            .unwrap_or(0)
+            .max("Title".len());
+        let total: Duration = self.results.iter().map(BenchResult::total).sum();
+        let time = |d: Duration| format!("{d:.1?}");
+
+        let runs = if self.options.warmup == 1 {
+            "run"
+        } else {
+            "runs"
+        };
+        writeln!(
+            f,
+            "Median of up to {} samples, after {} warm-up {runs}",
+            self.options.samples, self.options.warmup
+        )?;
+        writeln!(
+            f,
+            "{:>3}  {:title_width$}  {:>10}  {:>10}  {:>10}  {:>10}  {:>5}",
+            "Day", "Title", "Parse", "Part 1", "Part 2", "Total", "Share"
+        )?;
+
+        for result in &self.results {
+            let share = if total.is_zero() {
+                0.0
+            } else {
+                100.0 * result.total().as_secs_f64() / total.as_secs_f64()
+            };
+
+            writeln!(
+                f,
+                "{:>3}  {:title_width$}  {:>10}  {:>10}  {:>10}  {:>10}  {:>4.1}%",
+                result.day,
+                result.title,
+                time(result.parse.median),
+                time(result.part1.median),
+                time(result.part2.median),
+                time(result.total()),
+                share
+            )?;
+        }
+
+        writeln!(
+            f,
+            "{:>3}  {:title_width$}  {:>10}  {:>10}  {:>10}  {:>10}",
+            "",
+            "Total",
+            "",
+            "",
+            "",
+            time(total)
+        )
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// The median times of each stage of a day, from an earlier run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BaselineDay {
+    pub day: u8,
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl BaselineDay {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+
+    fn from_json(json: &Json) -> Result<Self, ParseError> {
+        let number = |value: Option<&Json>, what: &str| match value {
+            Some(Json::Number(n)) => Ok(*n),
+            _ => Err(ParseError::new(format!("Expected a number for {what}"))),
+        };
+
+        let day = number(json.get("day"), "the day")?;
+        let day = u8::try_from(day).map_err(|_| ParseError::invalid("day", &day.to_string()))?;
+        let median = |stage| {
+            let median = json.get(stage).and_then(|stats| stats.get("median_ns"));
+            number(median, &format!("the day {day} {stage} median")).map(Duration::from_nanos)
+        };
+
+        Ok(BaselineDay {
+            day,
+            parse: median("parse")?,
+            part1: median("part1")?,
+            part2: median("part2")?,
+        })
+    }
+}
+
+/// The median times of an earlier run, read from the JSON written by
+/// [`BenchReport::to_json`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baseline {
+    pub days: Vec<BaselineDay>,
+}
+
+impl Baseline {
+    pub fn get(&self, day: u8) -> Option<&BaselineDay> {
+        self.days.iter().find(|baseline| baseline.day == day)
+    }
+}
+
+impl FromStr for Baseline {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut reader = JsonReader { text: s, pos: 0 };
+        let json = reader.value()?;
+
+        if reader.peek().is_some() {
+            return Err(reader.error("Unexpected text after the report"));
+        }
+
+        let Some(Json::Array(days)) = json.get("days") else {
+            return Err(ParseError::new("Expected a list of days in the report"));
+        };
+
+        let days = days
+            .iter()
+            .map(BaselineDay::from_json)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Baseline { days })
+    }
+}
+
+/// Just enough of JSON to read back a [`BenchReport`]: the only numbers are
+/// whole numbers of nanoseconds or samples
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Json {
+    Number(u64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    /// The value of an object's field
+    fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(fields) => fields
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+}
+
+struct JsonReader<'a> {
+    text: &'a str,
+    /// The byte offset of the next character to read
+    pos: usize,
+}
+
+impl JsonReader<'_> {
+    /// An error at the current position
+    fn error(&self, message: &str) -> ParseError {
+        let before = &self.text[..self.pos];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        ParseError::new(message)
+            .line(before.matches('\n').count() + 1)
+            .column(before[line_start..].chars().count() + 1)
+    }
+
+    /// The next character that isn't whitespace, without reading it
+    fn peek(&mut self) -> Option<char> {
+        let rest = &self.text[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+
+        self.text[self.pos..].chars().next()
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        if self.peek() != Some(expected) {
+            return Err(self.error(&format!("Expected '{expected}'")));
+        }
+        self.pos += expected.len_utf8();
+
+        Ok(())
+    }
+
+    fn value(&mut self) -> Result<Json, ParseError> {
+        match self.peek() {
+            Some('{') => self.object(),
+            Some('[') => self.array(),
+            Some('"') => self.string().map(Json::String),
+            Some(ch) if ch.is_ascii_digit() => self.number(),
+            _ => Err(self.error("Expected a value")),
+        }
+    }
+
+    /// Comma separated items, up to and including the `close` character
+    fn items<T>(
+        &mut self,
+        close: char,
+        mut item: impl FnMut(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut items = Vec::new();
+
+        if self.peek() == Some(close) {
+            self.pos += close.len_utf8();
+            return Ok(items);
+        }
+
+        loop {
+            items.push(item(self)?);
+
+            if self.peek() == Some(',') {
+                self.pos += 1;
+            } else {
+                self.expect(close)?;
+                return Ok(items);
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Json, ParseError> {
+        self.expect('{')?;
+
+        self.items('}', |reader| {
+            let key = reader.string()?;
+            reader.expect(':')?;
+
+            Ok((key, reader.value()?))
+        })
+        .map(Json::Object)
+    }
+
+    fn array(&mut self) -> Result<Json, ParseError> {
+        self.expect('[')?;
+
+        self.items(']', Self::value).map(Json::Array)
+    }
+
+    fn number(&mut self) -> Result<Json, ParseError> {
+        let rest = &self.text[self.pos..];
+        let len = rest
+            .find(|ch: char| !ch.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let number = rest[..len]
+            .parse()
+            .map_err(|_| self.error("Number out of range"))?;
+        self.pos += len;
+
+        Ok(Json::Number(number))
+    }
+
+    fn string(&mut self) -> Result<String, ParseError> {
+        self.expect('"')?;
+
+        let mut string = String::new();
+        let mut chars = self.text[self.pos..].char_indices();
+
+        while let Some((i, ch)) = chars.next() {
+            let escaped = match ch {
+                '"' => {
+                    self.pos += i + 1;
+                    return Ok(string);
+                }
+                '\\' => chars.next().map(|(_, ch)| ch),
+                ch => {
+                    string.push(ch);
+                    continue;
+                }
+            };
+
+            let ch = match escaped {
+                Some('"' | '\\' | '/') => escaped,
+                Some('n') => Some('\n'),
+                Some('r') => Some('\r'),
+                Some('t') => Some('\t'),
+                Some('u') => {
+                    let hex: String = chars.by_ref().take(4).map(|(_, ch)| ch).collect();
+                    u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32)
+                }
+                _ => None,
+            };
+
+            match ch {
+                Some(ch) => string.push(ch),
+                None => {
+                    self.pos += i;
+                    return Err(self.error("Invalid escape in string"));
+                }
+            }
+        }
+
+        self.pos = self.text.len();
+        Err(self.error("Unterminated string"))
+    }
+}
+
+/// A report beside an earlier run, displayed as a table of the median times
+/// with how much each has changed
+pub struct Comparison<'a> {
+    report: &'a BenchReport,
+    baseline: &'a Baseline,
+}
+
+impl Display for Comparison<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let BenchReport { options, results } = self.report;
+        let title_width = results
+            .iter()
+            .map(|result| result.title.len())
+            .max()
+            .unwrap_or(0)
+            .max("Title".len());
+
+        // The time, and its change since the earlier run if there was one
+        let cell = |now: Duration, before: Option<Duration>| {
+            let change = match before {
+                Some(before) if before.is_zero() => String::new(),
+                Some(before) => format!(
+                    "{:+.1}%",
+                    100.0 * (now.as_secs_f64() / before.as_secs_f64() - 1.0)
+                ),
+                None => "new".to_string(),
+            };
+
+            format!("{:>9} {change:>8}", format!("{now:.1?}"))
+        };
+
+        let runs = if options.warmup == 1 { "run" } else { "runs" };
+        writeln!(
+            f,
+            "Median of up to {} samples, after {} warm-up {runs}, against an earlier run",
+            options.samples, options.warmup
+        )?;
+        writeln!(
+            f,
+            "{:>3}  {:title_width$}  {:>18}  {:>18}  {:>18}  {:>18}",
+            "Day", "Title", "Parse", "Part 1", "Part 2", "Total"
+        )?;
+
+        for result in results {
+            let before = self.baseline.get(result.day);
+
+            writeln!(
+                f,
+                "{:>3}  {:title_width$}  {}  {}  {}  {}",
+                result.day,
+                result.title,
+                cell(result.parse.median, before.map(|b| b.parse)),
+                cell(result.part1.median, before.map(|b| b.part1)),
+                cell(result.part2.median, before.map(|b| b.part2)),
+                cell(result.total(), before.map(BaselineDay::total)),
+            )?;
+        }
+
+        // Only compare the totals if the earlier run timed all the same days
+        let total: Duration = results.iter().map(BenchResult::total).sum();
+        let total_before = results
+            .iter()
+            .map(|result| self.baseline.get(result.day).map(BaselineDay::total))
+            .sum::<Option<Duration>>();
+
+        writeln!(
+            f,
+            "{:>3}  {:title_width$}  {:>18}  {:>18}  {:>18}  {}",
+            "",
+            "Total",
+            "",
+            "",
+            "",
+            cell(total, total_before)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(times: &[u64]) -> Vec<Duration> {
+        times.iter().map(|&t| Duration::from_millis(t)).collect()
+    }
+
+    fn result(day: u8, title: &'static str, times: [u64; 3]) -> BenchResult {
+        let [parse, part1, part2] = times.map(|t| Stats::from_times(ms(&[t])));
+
+        BenchResult {
+            day,
+            title,
+            parse,
+            part1,
+            part2,
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_times(ms(&[5, 1, 3]));
+        assert_eq!(stats.samples, 3);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(3));
+        assert_eq!(stats.max, Duration::from_millis(5));
+
+        let stats = Stats::from_times(ms(&[4, 1, 2, 9]));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(4));
+    }
+
+    #[test]
+    fn test_sample_count() {
+        let mut runs = 0;
+        let options = BenchOptions {
+            warmup: 2,
+            samples: 5,
+            ..BenchOptions::default()
+        };
+        assert_eq!(sample(&options, || runs += 1).samples, 5);
+        assert_eq!(runs, 7);
+
+        // The time limit still allows one sample
+        let options = BenchOptions {
+            warmup: 0,
+            time_limit: Duration::ZERO,
+            ..options
+        };
+        assert_eq!(sample(&options, || ()).samples, 1);
+    }
+
+    #[test]
+    fn test_json_string() {
+        let json = |s| {
+            let mut out = String::new();
+            write_json_string(&mut out, s);
+            out
+        };
+
+        assert_eq!(json("Rope Bridge"), r#""Rope Bridge""#);
+        assert_eq!(json(r#"Say "hi" \ bye"#), r#""Say \"hi\" \\ bye""#);
+        assert_eq!(json("a\tb\n\u{1}"), r#""a\tb\n\u0001""#);
+        assert_eq!(json("Café ☃"), r#""Café ☃""#);
+    }
+
+    #[test]
+    fn test_report() {
+        let report = BenchReport {
+            options: BenchOptions::default(),
+            results: vec![result(1, "One", [1, 2, 1]), result(2, "Two", [2, 10, 4])],
+        };
+
+        assert_eq!(
+            report.to_string(),
+            [
+                "Median of up to 10 samples, after 1 warm-up run",
+                "Day  Title       Parse      Part 1      Part 2       Total  Share",
+                "  1  One         1.0ms       2.0ms       1.0ms       4.0ms  20.0%",
+                "  2  Two         2.0ms      10.0ms       4.0ms      16.0ms  80.0%",
+                "     Total                                          20.0ms",
+                "",
+            ]
+            .join("\n")
+        );
+
+        assert_eq!(
+            report.to_json(),
+            [
+                "{",
+                r#"  "options": {"warmup": 1, "samples": 10, "time_limit_ns": 2000000000},"#,
+                r#"  "days": ["#,
+                r#"    {"day": 1, "title": "One", "parse": {"samples": 1, "min_ns": 1000000, "median_ns": 1000000, "mean_ns": 1000000, "max_ns": 1000000}, "part1": {"samples": 1, "min_ns": 2000000, "median_ns": 2000000, "mean_ns": 2000000, "max_ns": 2000000}, "part2": {"samples": 1, "min_ns": 1000000, "median_ns": 1000000, "mean_ns": 1000000, "max_ns": 1000000}, "total_ns": 4000000},"#,
+                r#"    {"day": 2, "title": "Two", "parse": {"samples": 1, "min_ns": 2000000, "median_ns": 2000000, "mean_ns": 2000000, "max_ns": 2000000}, "part1": {"samples": 1, "min_ns": 10000000, "median_ns": 10000000, "mean_ns": 10000000, "max_ns": 10000000}, "part2": {"samples": 1, "min_ns": 4000000, "median_ns": 4000000, "mean_ns": 4000000, "max_ns": 4000000}, "total_ns": 16000000}"#,
+                "  ]",
+                "}",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    fn medians(day: u8, times: [u64; 3]) -> BaselineDay {
+        let [parse, part1, part2] = times.map(Duration::from_millis);
+
+        BaselineDay {
+            day,
+            parse,
+            part1,
+            part2,
+        }
+    }
+
+    #[test]
+    fn test_baseline() {
+        let report = BenchReport {
+            options: BenchOptions::default(),
+            results: vec![
+                result(1, "One", [1, 2, 1]),
+                result(2, "Say \"two\"\t\\", [2, 10, 4]),
+            ],
+        };
+
+        assert_eq!(
+            report.to_json().parse(),
+            Ok(Baseline {
+                days: vec![medians(1, [1, 2, 1]), medians(2, [2, 10, 4])]
+            })
+        );
+
+        let json = r#"{"days":[{"title":"é","day":3,"parse":{"median_ns":5},"part1":{"median_ns":6},"part2":{"median_ns":7}}]}"#;
+        let day = json.parse::<Baseline>().unwrap().days[0];
+        assert_eq!((day.day, day.total()), (3, Duration::from_nanos(18)));
+
+        let empty = BenchReport {
+            results: Vec::new(),
+            ..report
+        };
+        assert_eq!(empty.to_json().parse(), Ok(Baseline::default()));
+    }
+
+    #[test]
+    fn test_invalid_baseline() {
+        let err = |json: &str| json.parse::<Baseline>().unwrap_err().to_string();
+
+        assert_eq!(err(""), "Line 1, column 1: Expected a value");
+        assert_eq!(
+            err("{\n  \"days\": [}"),
+            "Line 2, column 12: Expected a value"
+        );
+        assert_eq!(
+            err(r#"{"days": "\q"}"#),
+            "Line 1, column 11: Invalid escape in string"
+        );
+        assert_eq!(
+            err(r#"{"days": []} []"#),
+            "Line 1, column 14: Unexpected text after the report"
+        );
+        assert_eq!(
+            err(r#"{"day": 1}"#),
+            "Expected a list of days in the report"
+        );
+        assert_eq!(
+            err(r#"{"days": [{"day": 1, "parse": {"median_ns": 5}}]}"#),
+            "Expected a number for the day 1 part1 median"
+        );
+        assert_eq!(err(r#"{"days": [{"day": 300}]}"#), "Invalid day \"300\"");
+    }
+
+    #[test]
+    fn test_comparison() {
+        let report = BenchReport {
+            options: BenchOptions::default(),
+            results: vec![result(1, "One", [1, 2, 1]), result(2, "Two", [2, 10, 4])],
+        };
+        let baseline = Baseline {
+            days: vec![medians(1, [1, 4, 0]), medians(3, [1, 1, 1])],
+        };
+
+        assert_eq!(
+            report.compare(&baseline).to_string(),
+            [
+                "Median of up to 10 samples, after 1 warm-up run, against an earlier run",
+                "Day  Title               Parse              Part 1              Part 2               Total",
+                "  1  One        1.0ms    +0.0%      2.0ms   -50.0%      1.0ms               4.0ms   -20.0%",
+                "  2  Two        2.0ms      new     10.0ms      new      4.0ms      new     16.0ms      new",
+                "     Total                                                                 20.0ms      new",
+                "",
+            ]
+            .join("\n")
+        );
+
+        // With every day in the earlier run, the totals can be compared too
+        let baseline = Baseline {
+            days: vec![medians(2, [2, 10, 4]), medians(1, [1, 2, 1])],
+        };
+        let table = report.compare(&baseline).to_string();
+        assert!(table.lines().nth(4).unwrap().ends_with("20.0ms    +0.0%"));
+    }
+}
@@ -1,7 +1,171 @@
-#[derive(Debug, PartialEq, Copy, Clone)]
+use crate::Location;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
 pub enum Direction {
     Up,
     Down,
     Left,
     Right,
-}
\ No newline at end of file
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+}
+
+use Direction::*;
+
+impl Direction {
+    /// Every direction, clockwise, starting with `Up`
+    pub const ALL: [Direction; 8] = [Up, UpRight, Right, DownRight, Down, DownLeft, Left, UpLeft];
+
+    /// The four non-diagonal directions, clockwise, starting with `Up`
+    pub const CARDINAL: [Direction; 4] = [Up, Right, Down, Left];
+
+    /// The offset of a single step in this direction.
+    ///
+    /// The y axis points down, as with grid rows: `Up` is `(0, -1)`,
+    /// and `Right` is `(1, 0)`.
+    pub fn delta(self) -> Location {
+        match self {
+            Up => Location::new(0, -1),
+            Down => Location::new(0, 1),
+            Left => Location::new(-1, 0),
+            Right => Location::new(1, 0),
+            UpLeft => Location::new(-1, -1),
+            UpRight => Location::new(1, -1),
+            DownLeft => Location::new(-1, 1),
+            DownRight => Location::new(1, 1),
+        }
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        matches!(self, UpLeft | UpRight | DownLeft | DownRight)
+    }
+
+    /// A quarter turn clockwise
+    pub fn turn_right(self) -> Self {
+        match self {
+            Up => Right,
+            Right => Down,
+            Down => Left,
+            Left => Up,
+            UpRight => DownRight,
+            DownRight => DownLeft,
+            DownLeft => UpLeft,
+            UpLeft => UpRight,
+        }
+    }
+
+    /// A quarter turn anticlockwise
+    pub fn turn_left(self) -> Self {
+        self.opposite().turn_right()
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    /// An arrow pointing this way, for drawing paths
+    pub fn arrow(self) -> char {
+        match self {
+            Up => '^',
+            Down => 'v',
+            Left => '<',
+            Right => '>',
+            UpLeft => '↖',
+            UpRight => '↗',
+            DownLeft => '↙',
+            DownRight => '↘',
+        }
+    }
+}
+
+/// Error from parsing a `Direction`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDirectionError(pub String);
+
+impl fmt::Display for ParseDirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid direction: {:?}", self.0)
+    }
+}
+
+impl Error for ParseDirectionError {}
+
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    /// Parse a direction from `U`/`D`/`L`/`R`, compass points (`N`, `SE`, etc.),
+    /// or an arrow (`^`, `v`, `<`, `>`, `↑`, `↘`, etc.)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let dir = match s {
+            "U" | "N" | "^" | "↑" => Up,
+            "D" | "S" | "v" | "↓" => Down,
+            "L" | "W" | "<" | "←" => Left,
+            "R" | "E" | ">" | "→" => Right,
+            "UL" | "NW" | "↖" => UpLeft,
+            "UR" | "NE" | "↗" => UpRight,
+            "DL" | "SW" | "↙" => DownLeft,
+            "DR" | "SE" | "↘" => DownRight,
+            _ => return Err(ParseDirectionError(s.to_string())),
+        };
+
+        Ok(dir)
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = ParseDirectionError;
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        ch.encode_utf8(&mut [0; 4]).parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.opposite().opposite(), dir);
+            assert_eq!(dir.opposite().delta(), -dir.delta());
+            assert_eq!(dir.is_diagonal(), !Direction::CARDINAL.contains(&dir));
+        }
+
+        assert_eq!(Up.turn_right(), Right);
+        assert_eq!(Up.turn_left(), Left);
+        assert_eq!(UpLeft.turn_right(), UpRight);
+        assert_eq!(DownRight.opposite(), UpLeft);
+    }
+
+    #[test]
+    fn test_delta() {
+        assert_eq!(Up.delta(), Location::new(0, -1));
+        assert_eq!(DownLeft.delta(), Location::new(-1, 1));
+        assert_eq!(Up.delta() + Right.delta(), UpRight.delta());
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("U".parse(), Ok(Up));
+        assert_eq!("W".parse(), Ok(Left));
+        assert_eq!("SE".parse(), Ok(DownRight));
+        assert_eq!("v".parse(), Ok(Down));
+        assert_eq!(Direction::try_from('→'), Ok(Right));
+        assert_eq!(Direction::try_from('↖'), Ok(UpLeft));
+        assert_eq!(
+            "X".parse::<Direction>(),
+            Err(ParseDirectionError("X".to_string()))
+        );
+
+        for dir in Direction::ALL {
+            assert_eq!(Direction::try_from(dir.arrow()), Ok(dir));
+        }
+    }
+}
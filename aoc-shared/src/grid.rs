@@ -1,6 +1,28 @@
-/// Reusing part of day 8's solution for a virtual 2d grid
+//! Reusing part of day 8's solution for a virtual 2d grid
+use crate::enums::Direction;
+use crate::Location;
+use std::error::Error;
+use std::fmt;
 
-#[derive(Debug)]
+/// Offsets of the cells directly above, below, left, and right of a cell
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
+
+/// Offsets of the cells touching the corners of a cell
+pub const DIAGONAL: [(isize, isize); 4] = [(-1, -1), (1, -1), (-1, 1), (1, 1)];
+
+/// Offsets of all eight cells surrounding a cell
+pub const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct Grid<T> {
     width: usize,
     pub vec: Vec<T>,
@@ -14,6 +36,47 @@ impl<T> Grid<T> {
         }
     }
 
+    /// Build a grid from lines of text, mapping each character with its x,y coordinate
+    /// to a cell. The width of the grid is the width of the first line, and every
+    /// other line must match it. CRLF line endings and trailing newlines are ignored.
+    pub fn parse<F, E>(input: &str, mut f: F) -> Result<Self, GridParseError<E>>
+    where
+        F: FnMut(char, (usize, usize)) -> Result<T, E>,
+    {
+        let input = input.trim_end_matches(['\r', '\n']);
+        if input.is_empty() {
+            return Err(GridParseError::Empty);
+        }
+
+        let mut lines = input.lines().peekable();
+        let width = lines.peek().map(|line| line.chars().count()).unwrap();
+        let mut grid = Grid::new(width);
+
+        for (y, line) in lines.enumerate() {
+            let found = line.chars().count();
+            if found != width {
+                return Err(GridParseError::RaggedRow {
+                    line: y + 1,
+                    column: found.min(width) + 1,
+                    expected: width,
+                    found,
+                });
+            }
+
+            for (x, ch) in line.chars().enumerate() {
+                let cell = f(ch, (x, y)).map_err(|error| GridParseError::InvalidCell {
+                    line: y + 1,
+                    column: x + 1,
+                    error,
+                })?;
+
+                grid.vec.push(cell);
+            }
+        }
+
+        Ok(grid)
+    }
+
     pub fn len(&self) -> usize {
         self.vec.len()
     }
@@ -26,7 +89,13 @@ impl<T> Grid<T> {
         self.width
     }
 
+    /// The number of complete rows in the grid. Cells past the last complete
+    /// row can only be reached by index.
     pub fn num_rows(&self) -> usize {
+        if self.num_cols() == 0 {
+            return 0;
+        }
+
         self.len() / self.num_cols()
     }
 
@@ -35,8 +104,13 @@ impl<T> Grid<T> {
         (y * self.num_cols()) + x
     }
 
-    /// Convert linear array index to x,y coordinate
+    /// Convert linear array index to x,y coordinate.
+    /// A grid with no columns puts every index in row 0.
     pub fn idx_xy(&self, idx: usize) -> (usize, usize) {
+        if self.num_cols() == 0 {
+            return (idx, 0);
+        }
+
         (idx % self.num_cols(), idx / self.num_cols())
     }
 
@@ -48,79 +122,654 @@ impl<T> Grid<T> {
         self.vec.get_mut(idx)
     }
 
-    pub fn get_row(&mut self, row_num: usize) -> &mut [T] {
-        let start = self.row_first_idx(row_num);
-        let end = self.row_last_idx(row_num);
-
-        &mut self.vec[start..=end]
+    /// Is the x,y coordinate within the grid?
+    pub fn in_bounds(&self, x: usize, y: usize) -> bool {
+        x < self.num_cols() && y < self.num_rows()
     }
 
-    pub fn row_first_idx(&self, row: usize) -> usize {
-        let idx = row * self.num_cols();
+    /// Is the linear array index within the complete rows of the grid?
+    pub fn in_bounds_idx(&self, idx: usize) -> bool {
+        idx < self.num_cols() * self.num_rows()
+    }
 
-        if idx < self.len() {
-            idx
+    /// Convert x,y coordinate into linear array index, if it is within the grid
+    pub fn checked_xy_idx(&self, x: usize, y: usize) -> Option<usize> {
+        if self.in_bounds(x, y) {
+            Some(self.xy_idx(x, y))
         } else {
-            self.len()
+            None
         }
     }
 
-    pub fn row_last_idx(&self, row: usize) -> usize {
-        if (row + 1) > self.num_rows() {
-            return self.len();
+    pub fn get_xy(&self, x: usize, y: usize) -> Option<&T> {
+        self.checked_xy_idx(x, y).and_then(|idx| self.get(idx))
+    }
+
+    pub fn get_xy_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.checked_xy_idx(x, y).and_then(|idx| self.get_mut(idx))
+    }
+
+    /// Replace the value at the x,y coordinate, returning the previous value.
+    /// Returns `None`, and leaves the grid untouched, if the coordinate is outside the grid.
+    pub fn set_xy(&mut self, x: usize, y: usize, value: T) -> Option<T> {
+        self.get_xy_mut(x, y)
+            .map(|cell| std::mem::replace(cell, value))
+    }
+
+    /// Get the index of the cell `dx` columns and `dy` rows away from `idx`,
+    /// without wrapping around the edges of the grid
+    pub fn offset_idx(&self, idx: usize, dx: isize, dy: isize) -> Option<usize> {
+        if !self.in_bounds_idx(idx) {
+            return None;
         }
 
-        self.row_first_idx(row + 1) - 1
+        let (x, y) = self.idx_xy(idx);
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+
+        self.checked_xy_idx(x, y)
+    }
+
+    pub fn get_offset(&self, idx: usize, dx: isize, dy: isize) -> Option<&T> {
+        self.offset_idx(idx, dx, dy).and_then(|i| self.get(i))
+    }
+
+    pub fn get_offset_mut(&mut self, idx: usize, dx: isize, dy: isize) -> Option<&mut T> {
+        self.offset_idx(idx, dx, dy).and_then(|i| self.get_mut(i))
+    }
+
+    /// Iterate over the cells above, below, left, and right of `idx`
+    pub fn neighbors(&self, idx: usize) -> Neighbors<'_, T> {
+        self.stencil_neighbors(idx, &ORTHOGONAL)
     }
 
-    pub fn get_row_indexes(&self, row_num: usize) -> Vec<usize> {
-        (self.row_first_idx(row_num)..=self.row_last_idx(row_num)).collect()
+    /// Iterate over the cells touching the corners of `idx`
+    pub fn diagonal_neighbors(&self, idx: usize) -> Neighbors<'_, T> {
+        self.stencil_neighbors(idx, &DIAGONAL)
     }
 
-    pub fn get_column_indexes(&self, col_num: usize) -> Vec<usize> {
-        let mut indexes = Vec::new();
+    /// Iterate over all eight cells surrounding `idx`
+    pub fn all_neighbors(&self, idx: usize) -> Neighbors<'_, T> {
+        self.stencil_neighbors(idx, &SURROUNDING)
+    }
 
+    /// Iterate over the cells at each `(dx, dy)` offset from `idx`
+    pub fn stencil_neighbors<'a>(
+        &'a self,
+        idx: usize,
+        offsets: &'a [(isize, isize)],
+    ) -> Neighbors<'a, T> {
+        Neighbors {
+            grid: self,
+            idx,
+            offsets: offsets.iter(),
+            wrap: false,
+        }
+    }
+
+    /// Walk from `from` towards the edge of the grid in the given direction.
+    /// The starting cell is not included.
+    pub fn ray(&self, from: usize, dir: Direction) -> Ray<'_, T> {
+        let Location { x: dx, y: dy } = dir.delta();
+
+        self.ray_offset(from, dx, dy)
+    }
+
+    /// Walk from `from` in steps of `dx` columns and `dy` rows, such as `(1, 1)`
+    /// for a diagonal, until leaving the grid. The starting cell is not included,
+    /// and a step of `(0, 0)` goes nowhere, so its ray is empty.
+    pub fn ray_offset(&self, from: usize, dx: isize, dy: isize) -> Ray<'_, T> {
+        Ray {
+            grid: self,
+            idx: from,
+            dx,
+            dy,
+        }
+    }
+
+    /// Cast a ray from `idx` in each of the eight directions,
+    /// clockwise, starting with `Direction::Up`
+    pub fn rays_from(&self, idx: usize) -> impl Iterator<Item = (Direction, Ray<'_, T>)> {
+        Direction::ALL
+            .into_iter()
+            .map(move |dir| (dir, self.ray(idx, dir)))
+    }
+
+    pub fn row(&self, row_num: usize) -> Option<&[T]> {
+        let start = self.row_first_idx(row_num)?;
+        let end = self.row_last_idx(row_num)?;
+
+        Some(&self.vec[start..=end])
+    }
+
+    pub fn get_row(&mut self, row_num: usize) -> Option<&mut [T]> {
+        let start = self.row_first_idx(row_num)?;
+        let end = self.row_last_idx(row_num)?;
+
+        Some(&mut self.vec[start..=end])
+    }
+
+    pub fn row_first_idx(&self, row: usize) -> Option<usize> {
+        if row < self.num_rows() {
+            Some(row * self.num_cols())
+        } else {
+            None
+        }
+    }
+
+    pub fn row_last_idx(&self, row: usize) -> Option<usize> {
+        self.row_first_idx(row)
+            .map(|first| first + self.num_cols() - 1)
+    }
+
+    pub fn get_row_indexes(&self, row_num: usize) -> Option<Vec<usize>> {
+        let start = self.row_first_idx(row_num)?;
+        let end = self.row_last_idx(row_num)?;
+
+        Some((start..=end).collect())
+    }
+
+    pub fn get_column_indexes(&self, col_num: usize) -> Option<Vec<usize>> {
         if col_num >= self.num_cols() {
-            panic!(
-                "Asked for column {}, there are {} columns",
-                col_num,
-                self.num_cols()
-            );
+            return None;
         }
 
-        for r in 0..self.num_rows() {
-            let idx = self.num_cols() * r + col_num;
-            indexes.push(idx);
+        let indexes = (0..self.num_rows())
+            .map(|r| self.xy_idx(col_num, r))
+            .collect();
+
+        Some(indexes)
+    }
+}
+
+/// Iterator over the `(index, cell)` pairs around a grid cell.
+///
+/// Offsets that fall outside the grid are skipped, unless the iterator
+/// has been switched to [`Neighbors::wrapping`] mode.
+#[derive(Debug, Clone)]
+pub struct Neighbors<'a, T> {
+    grid: &'a Grid<T>,
+    idx: usize,
+    offsets: std::slice::Iter<'a, (isize, isize)>,
+    wrap: bool,
+}
+
+impl<'a, T> Neighbors<'a, T> {
+    /// Wrap offsets that fall off one edge of the grid around to the opposite edge
+    pub fn wrapping(mut self) -> Self {
+        self.wrap = true;
+
+        self
+    }
+
+    fn wrapped_idx(&self, dx: isize, dy: isize) -> Option<usize> {
+        let grid = self.grid;
+        if !grid.in_bounds_idx(self.idx) {
+            return None;
         }
 
-        indexes
+        let (x, y) = grid.idx_xy(self.idx);
+        let cols = grid.num_cols() as isize;
+        let rows = grid.num_rows() as isize;
+        let x = (x as isize + dx).rem_euclid(cols) as usize;
+        let y = (y as isize + dy).rem_euclid(rows) as usize;
+
+        grid.checked_xy_idx(x, y)
     }
 }
 
-#[macro_export]
-/// Simplifies newtype wrapping of the `Grid` struct
-macro_rules! impl_grid_newtype {
-    ($($struct: tt, $target: path, $type: ty),* ) => {
-        $(
-            impl ::core::ops::Deref for $struct<$type> {
-                type Target = $target;
+impl<'a, T> Iterator for Neighbors<'a, T> {
+    type Item = (usize, &'a T);
 
-                fn deref(&self) -> &Self::Target {
-                    &self.0
-                }
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(&(dx, dy)) = self.offsets.next() {
+            let idx = if self.wrap {
+                self.wrapped_idx(dx, dy)
+            } else {
+                self.grid.offset_idx(self.idx, dx, dy)
+            };
+
+            if let Some(idx) = idx {
+                return Some((idx, &self.grid.vec[idx]));
             }
+        }
 
-            impl ::core::ops::DerefMut for $struct<$type> {
-                fn deref_mut(&mut self) -> &mut Self::Target {
-                    &mut self.0
-                }
+        None
+    }
+}
+
+/// Reasons text could not be turned into a [`Grid`].
+/// Line and column numbers start at 1.
+#[derive(Debug, PartialEq)]
+pub enum GridParseError<E> {
+    /// There were no lines to parse
+    Empty,
+    /// A line was not the same width as the first line
+    RaggedRow {
+        line: usize,
+        column: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// The cell mapping function rejected a character
+    InvalidCell {
+        line: usize,
+        column: usize,
+        error: E,
+    },
+}
+
+impl<E: fmt::Display> fmt::Display for GridParseError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridParseError::Empty => write!(f, "No grid rows to parse"),
+            GridParseError::RaggedRow {
+                line,
+                column,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Line {line}, column {column}: expected {expected} columns, found {found}"
+            ),
+            GridParseError::InvalidCell {
+                line,
+                column,
+                error,
+            } => write!(f, "Line {line}, column {column}: {error}"),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for GridParseError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GridParseError::InvalidCell { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Lazy iterator over the `(index, cell)` pairs in a straight line from a grid cell
+#[derive(Debug, Clone)]
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    idx: usize,
+    dx: isize,
+    dy: isize,
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = (usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if (self.dx, self.dy) == (0, 0) {
+            return None;
+        }
+
+        let grid = self.grid;
+        let idx = grid.offset_idx(self.idx, self.dx, self.dy)?;
+        self.idx = idx;
+
+        Some((idx, &grid.vec[idx]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid_like::GridLike;
+    use proptest::prelude::*;
+
+    fn get_grid() -> Grid<usize> {
+        // 0 1 2
+        // 3 4 5
+        let mut grid = Grid::new(3);
+        grid.vec = (0..6).collect();
+
+        grid
+    }
+
+    fn parse_digit(ch: char, _: (usize, usize)) -> Result<u32, String> {
+        ch.to_digit(10).ok_or(format!("'{ch}' is not a digit"))
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("123\r\n456\r\n\r\n", parse_digit).unwrap();
+
+        assert_eq!(grid.num_cols(), 3);
+        assert_eq!(grid.num_rows(), 2);
+        assert_eq!(grid.vec, vec![1, 2, 3, 4, 5, 6]);
+
+        let coords = Grid::parse("ab\ncd\n", |_, xy| Ok::<_, ()>(xy)).unwrap();
+        assert_eq!(coords.get_xy(0, 1), Some(&(0, 1)));
+        assert_eq!(coords.get_xy(1, 0), Some(&(1, 0)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Grid::parse("\n", parse_digit), Err(GridParseError::Empty));
+        assert_eq!(
+            Grid::parse("123\n45\n678", parse_digit),
+            Err(GridParseError::RaggedRow {
+                line: 2,
+                column: 3,
+                expected: 3,
+                found: 2,
+            })
+        );
+        assert_eq!(
+            Grid::parse("123\n4567", parse_digit),
+            Err(GridParseError::RaggedRow {
+                line: 2,
+                column: 4,
+                expected: 3,
+                found: 4,
+            })
+        );
+
+        let err = Grid::parse("123\n4x6", parse_digit).unwrap_err();
+        assert_eq!(err.to_string(), "Line 2, column 2: 'x' is not a digit");
+    }
+
+    #[test]
+    fn test_in_bounds() {
+        let grid = get_grid();
+
+        assert!(grid.in_bounds(0, 0));
+        assert!(grid.in_bounds(2, 1));
+        assert!(!grid.in_bounds(3, 0));
+        assert!(!grid.in_bounds(0, 2));
+    }
+
+    #[test]
+    fn test_get_xy() {
+        let mut grid = get_grid();
+
+        assert_eq!(grid.get_xy(1, 1), Some(&4));
+        assert_eq!(grid.get_xy(3, 0), None, "x past the edge should not wrap");
+
+        *grid.get_xy_mut(0, 1).unwrap() = 30;
+        assert_eq!(grid.get(3), Some(&30));
+    }
+
+    #[test]
+    fn test_set_xy() {
+        let mut grid = get_grid();
+
+        assert_eq!(grid.set_xy(2, 0, 20), Some(2));
+        assert_eq!(grid.get_xy(2, 0), Some(&20));
+        assert_eq!(grid.set_xy(0, 5, 50), None);
+        assert_eq!(grid.vec, vec![0, 1, 20, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_offset_idx() {
+        let grid = get_grid();
+
+        assert_eq!(grid.offset_idx(4, 0, -1), Some(1));
+        assert_eq!(grid.offset_idx(4, 1, 0), Some(5));
+        assert_eq!(grid.offset_idx(4, -1, -1), Some(0));
+        assert_eq!(grid.offset_idx(2, 1, 0), None);
+        assert_eq!(grid.offset_idx(3, -1, 0), None);
+        assert_eq!(grid.offset_idx(0, 0, -1), None);
+        assert_eq!(grid.offset_idx(5, 0, 1), None);
+        assert_eq!(grid.get_offset(0, 2, 1), Some(&5));
+    }
+
+    fn neighbor_indexes(neighbors: Neighbors<'_, usize>) -> Vec<usize> {
+        neighbors.map(|(idx, _)| idx).collect()
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = get_grid();
+
+        assert_eq!(neighbor_indexes(grid.neighbors(0)), vec![3, 1]);
+        assert_eq!(neighbor_indexes(grid.neighbors(4)), vec![1, 3, 5]);
+        assert_eq!(neighbor_indexes(grid.diagonal_neighbors(4)), vec![0, 2]);
+        assert_eq!(neighbor_indexes(grid.all_neighbors(4)), vec![0, 1, 2, 3, 5]);
+        assert_eq!(
+            grid.neighbors(2).collect::<Vec<_>>(),
+            vec![(5, &5), (1, &1)]
+        );
+    }
+
+    #[test]
+    fn test_stencil_neighbors() {
+        let grid = get_grid();
+        let knight = [(2, 1), (-2, 1), (2, -1), (-2, -1)];
+
+        assert_eq!(
+            neighbor_indexes(grid.stencil_neighbors(0, &knight)),
+            vec![5]
+        );
+        assert_eq!(neighbor_indexes(grid.stencil_neighbors(4, &knight)), vec![]);
+    }
+
+    #[test]
+    fn test_wrapping_neighbors() {
+        let grid = get_grid();
+
+        assert_eq!(
+            neighbor_indexes(grid.neighbors(0).wrapping()),
+            vec![3, 3, 2, 1]
+        );
+        assert_eq!(
+            neighbor_indexes(grid.diagonal_neighbors(5).wrapping()),
+            vec![1, 0, 1, 0]
+        );
+        assert_eq!(neighbor_indexes(grid.neighbors(6).wrapping()), vec![]);
+    }
+
+    #[test]
+    fn test_ray() {
+        let mut grid = Grid::new(4);
+        grid.vec = (0..12).collect();
+
+        let ray = |from, dir| grid.ray(from, dir).map(|(idx, _)| idx).collect::<Vec<_>>();
+
+        assert_eq!(ray(5, Direction::Right), vec![6, 7]);
+        assert_eq!(ray(5, Direction::Left), vec![4]);
+        assert_eq!(ray(5, Direction::Up), vec![1]);
+        assert_eq!(ray(1, Direction::Down), vec![5, 9]);
+        assert_eq!(ray(0, Direction::DownRight), vec![5, 10]);
+        assert_eq!(ray(11, Direction::UpLeft), vec![6, 1]);
+        assert_eq!(ray(3, Direction::DownLeft), vec![6, 9]);
+        assert_eq!(ray(8, Direction::UpRight), vec![5, 2]);
+        assert_eq!(ray(3, Direction::Right), vec![]);
+
+        let knight = grid.ray_offset(0, 2, 1).map(|(idx, _)| idx);
+        assert_eq!(knight.collect::<Vec<_>>(), vec![6]);
+        assert_eq!(grid.ray_offset(5, 0, 0).count(), 0);
+    }
+
+    #[test]
+    fn test_rays_from() {
+        let grid = get_grid();
+
+        let rays: Vec<(Direction, Vec<usize>)> = grid
+            .rays_from(4)
+            .map(|(dir, ray)| (dir, ray.map(|(idx, _)| idx).collect()))
+            .collect();
+
+        assert_eq!(
+            rays,
+            vec![
+                (Direction::Up, vec![1]),
+                (Direction::UpRight, vec![2]),
+                (Direction::Right, vec![5]),
+                (Direction::DownRight, vec![]),
+                (Direction::Down, vec![]),
+                (Direction::DownLeft, vec![]),
+                (Direction::Left, vec![3]),
+                (Direction::UpLeft, vec![0]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_row_accessors() {
+        let mut grid = get_grid();
+        grid.vec.push(6);
+
+        assert_eq!(grid.num_rows(), 2, "Partial rows are not counted");
+        assert_eq!(grid.row_first_idx(1), Some(3));
+        assert_eq!(grid.row_last_idx(1), Some(5));
+        assert_eq!(grid.row_first_idx(2), None);
+        assert_eq!(grid.row_last_idx(2), None);
+        assert_eq!(grid.row(0), Some(&[0, 1, 2][..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.get_row(2), None);
+        assert_eq!(grid.get_row_indexes(1), Some(vec![3, 4, 5]));
+        assert_eq!(grid.get_row_indexes(2), None);
+        assert_eq!(grid.get_xy(0, 2), None);
+        assert_eq!(grid.offset_idx(6, 0, -1), None);
+    }
+
+    #[test]
+    fn test_column_accessors() {
+        let grid = get_grid();
+
+        assert_eq!(grid.get_column_indexes(0), Some(vec![0, 3]));
+        assert_eq!(grid.get_column_indexes(2), Some(vec![2, 5]));
+        assert_eq!(grid.get_column_indexes(3), None);
+    }
+
+    #[test]
+    fn test_zero_width() {
+        let mut grid: Grid<usize> = Grid::new(0);
+        grid.vec.push(1);
+
+        assert_eq!(grid.num_rows(), 0);
+        assert!(!grid.in_bounds(0, 0));
+        assert!(!grid.in_bounds_idx(0));
+        assert_eq!(grid.row(0), None);
+        assert_eq!(grid.get_column_indexes(0), None);
+        assert_eq!(grid.offset_idx(0, 0, 0), None);
+        assert_eq!(grid.neighbors(0).wrapping().count(), 0);
+        assert_eq!(grid.ray(0, Direction::Right).count(), 0);
+    }
+
+    fn any_grid() -> impl Strategy<Value = Grid<usize>> {
+        (0usize..8, 0usize..50).prop_map(|(width, len)| {
+            let mut grid = Grid::new(width);
+            grid.vec = (0..len).collect();
+
+            grid
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_num_rows_counts_complete_rows(grid in any_grid()) {
+            let rows = grid.num_rows();
+
+            prop_assert!(rows * grid.num_cols() <= grid.len());
+            if grid.num_cols() > 0 {
+                prop_assert!(grid.len() - rows * grid.num_cols() < grid.num_cols());
+            } else {
+                prop_assert_eq!(rows, 0);
             }
+        }
+
+        #[test]
+        fn prop_row_accessors_agree(grid in any_grid(), row in 0usize..12) {
+            let mut grid = grid;
+            let exists = row < grid.num_rows();
+            let indexes = grid.get_row_indexes(row);
+
+            prop_assert_eq!(grid.row_first_idx(row).is_some(), exists);
+            prop_assert_eq!(grid.row_last_idx(row).is_some(), exists);
+            prop_assert_eq!(indexes.is_some(), exists);
+
+            if let Some(indexes) = indexes {
+                let expected: Vec<usize> = indexes.iter().map(|&idx| grid.vec[idx]).collect();
+
+                prop_assert_eq!(indexes.len(), grid.num_cols());
+                prop_assert!(indexes.iter().all(|&idx| grid.in_bounds_idx(idx)));
+                prop_assert_eq!(grid.row(row).unwrap(), &expected[..]);
+                prop_assert_eq!(grid.get_row(row).unwrap().len(), grid.num_cols());
+            } else {
+                prop_assert!(grid.row(row).is_none());
+                prop_assert!(grid.get_row(row).is_none());
+            }
+        }
+
+        #[test]
+        fn prop_column_accessors_agree(grid in any_grid(), col in 0usize..12) {
+            let indexes = grid.get_column_indexes(col);
 
-            impl $struct<$type> {
-                pub fn new(width: usize) -> Self {
-                    $struct(<$target>::new(width))
+            prop_assert_eq!(indexes.is_some(), col < grid.num_cols());
+
+            if let Some(indexes) = indexes {
+                prop_assert_eq!(indexes.len(), grid.num_rows());
+
+                for (row, idx) in indexes.into_iter().enumerate() {
+                    prop_assert_eq!(grid.idx_xy(idx), (col, row));
+                    prop_assert!(grid.in_bounds_idx(idx));
                 }
             }
-        )*
+        }
+
+        #[test]
+        fn prop_coordinates_round_trip(grid in any_grid(), x in 0usize..12, y in 0usize..12) {
+            let idx = grid.checked_xy_idx(x, y);
+
+            prop_assert_eq!(idx.is_some(), grid.in_bounds(x, y));
+            prop_assert_eq!(grid.get_xy(x, y).is_some(), grid.in_bounds(x, y));
+
+            if let Some(idx) = idx {
+                prop_assert!(grid.in_bounds_idx(idx));
+                prop_assert_eq!(grid.idx_xy(idx), (x, y));
+                prop_assert_eq!(grid.row(y).unwrap().get(x), grid.get_xy(x, y));
+            }
+        }
+
+        #[test]
+        fn prop_indexes_round_trip(grid in any_grid(), idx in 0usize..60) {
+            let (x, y) = grid.idx_xy(idx);
+
+            prop_assert_eq!(GridLike::idx_xy(&grid, idx), (x, y));
+            prop_assert_eq!(grid.xy_idx(x, y), idx);
+            if grid.num_cols() == 0 {
+                prop_assert_eq!(y, 0);
+            }
+        }
+
+        #[test]
+        fn prop_offsets_stay_in_bounds(
+            grid in any_grid(),
+            idx in 0usize..60,
+            dx in -3isize..=3,
+            dy in -3isize..=3,
+        ) {
+            if let Some(to) = grid.offset_idx(idx, dx, dy) {
+                let (x, y) = grid.idx_xy(idx);
+                let (to_x, to_y) = grid.idx_xy(to);
+
+                prop_assert!(grid.in_bounds_idx(to));
+                prop_assert_eq!(to_x as isize - x as isize, dx);
+                prop_assert_eq!(to_y as isize - y as isize, dy);
+            }
+
+            for (n, _) in grid.all_neighbors(idx).chain(grid.all_neighbors(idx).wrapping()) {
+                prop_assert!(grid.in_bounds_idx(n));
+            }
+        }
+
+        #[test]
+        fn prop_rays_reach_the_edge(grid in any_grid(), idx in 0usize..60) {
+            for (dir, ray) in grid.rays_from(idx) {
+                let cells: Vec<usize> = ray.map(|(idx, _)| idx).collect();
+
+                prop_assert!(cells.iter().all(|&idx| grid.in_bounds_idx(idx)));
+
+                let Location { x: dx, y: dy } = dir.delta();
+                let end = cells.last().copied().unwrap_or(idx);
+                prop_assert_eq!(grid.offset_idx(end, dx, dy), None);
+            }
+        }
     }
 }
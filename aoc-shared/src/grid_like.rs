@@ -0,0 +1,223 @@
+//! Trait-based extension of [`Grid`]
+use crate::enums::Direction;
+use crate::grid::{Grid, Neighbors, Ray};
+use crate::render::Renderer;
+use crate::view::GridView;
+
+/// A type that is, or contains, a [`Grid`].
+///
+/// Implementors only need to provide `as_grid` and `as_grid_mut`; the rest of
+/// the grid API comes from the default methods. Day solutions can then add
+/// behaviour to a grid with a trait of their own, such as
+/// `trait Forest: GridLike<Cell = Tree>`, and helpers can be written once for
+/// any `G: GridLike`.
+pub trait GridLike {
+    type Cell;
+
+    fn as_grid(&self) -> &Grid<Self::Cell>;
+
+    fn as_grid_mut(&mut self) -> &mut Grid<Self::Cell>;
+
+    fn len(&self) -> usize {
+        self.as_grid().len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.as_grid().is_empty()
+    }
+
+    fn num_cols(&self) -> usize {
+        self.as_grid().num_cols()
+    }
+
+    fn num_rows(&self) -> usize {
+        self.as_grid().num_rows()
+    }
+
+    fn xy_idx(&self, x: usize, y: usize) -> usize {
+        self.as_grid().xy_idx(x, y)
+    }
+
+    fn idx_xy(&self, idx: usize) -> (usize, usize) {
+        self.as_grid().idx_xy(idx)
+    }
+
+    fn in_bounds(&self, x: usize, y: usize) -> bool {
+        self.as_grid().in_bounds(x, y)
+    }
+
+    fn in_bounds_idx(&self, idx: usize) -> bool {
+        self.as_grid().in_bounds_idx(idx)
+    }
+
+    fn checked_xy_idx(&self, x: usize, y: usize) -> Option<usize> {
+        self.as_grid().checked_xy_idx(x, y)
+    }
+
+    fn get(&self, idx: usize) -> Option<&Self::Cell> {
+        self.as_grid().get(idx)
+    }
+
+    fn get_mut(&mut self, idx: usize) -> Option<&mut Self::Cell> {
+        self.as_grid_mut().get_mut(idx)
+    }
+
+    fn get_xy(&self, x: usize, y: usize) -> Option<&Self::Cell> {
+        self.as_grid().get_xy(x, y)
+    }
+
+    fn get_xy_mut(&mut self, x: usize, y: usize) -> Option<&mut Self::Cell> {
+        self.as_grid_mut().get_xy_mut(x, y)
+    }
+
+    fn set_xy(&mut self, x: usize, y: usize, value: Self::Cell) -> Option<Self::Cell> {
+        self.as_grid_mut().set_xy(x, y, value)
+    }
+
+    fn offset_idx(&self, idx: usize, dx: isize, dy: isize) -> Option<usize> {
+        self.as_grid().offset_idx(idx, dx, dy)
+    }
+
+    fn get_offset(&self, idx: usize, dx: isize, dy: isize) -> Option<&Self::Cell> {
+        self.as_grid().get_offset(idx, dx, dy)
+    }
+
+    fn get_offset_mut(&mut self, idx: usize, dx: isize, dy: isize) -> Option<&mut Self::Cell> {
+        self.as_grid_mut().get_offset_mut(idx, dx, dy)
+    }
+
+    fn row(&self, row_num: usize) -> Option<&[Self::Cell]> {
+        self.as_grid().row(row_num)
+    }
+
+    fn get_row(&mut self, row_num: usize) -> Option<&mut [Self::Cell]> {
+        self.as_grid_mut().get_row(row_num)
+    }
+
+    fn row_first_idx(&self, row: usize) -> Option<usize> {
+        self.as_grid().row_first_idx(row)
+    }
+
+    fn row_last_idx(&self, row: usize) -> Option<usize> {
+        self.as_grid().row_last_idx(row)
+    }
+
+    fn get_row_indexes(&self, row_num: usize) -> Option<Vec<usize>> {
+        self.as_grid().get_row_indexes(row_num)
+    }
+
+    fn get_column_indexes(&self, col_num: usize) -> Option<Vec<usize>> {
+        self.as_grid().get_column_indexes(col_num)
+    }
+
+    /// Iterate over every `(index, cell)` pair of the grid
+    fn cells(&self) -> std::iter::Enumerate<std::slice::Iter<'_, Self::Cell>> {
+        self.as_grid().vec.iter().enumerate()
+    }
+
+    /// Find the index of the first cell matching the predicate
+    fn position(&self, predicate: impl Fn(&Self::Cell) -> bool) -> Option<usize> {
+        self.as_grid().vec.iter().position(predicate)
+    }
+
+    fn neighbors(&self, idx: usize) -> Neighbors<'_, Self::Cell> {
+        self.as_grid().neighbors(idx)
+    }
+
+    fn diagonal_neighbors(&self, idx: usize) -> Neighbors<'_, Self::Cell> {
+        self.as_grid().diagonal_neighbors(idx)
+    }
+
+    fn all_neighbors(&self, idx: usize) -> Neighbors<'_, Self::Cell> {
+        self.as_grid().all_neighbors(idx)
+    }
+
+    fn stencil_neighbors<'a>(
+        &'a self,
+        idx: usize,
+        offsets: &'a [(isize, isize)],
+    ) -> Neighbors<'a, Self::Cell> {
+        self.as_grid().stencil_neighbors(idx, offsets)
+    }
+
+    fn ray(&self, from: usize, dir: Direction) -> Ray<'_, Self::Cell> {
+        self.as_grid().ray(from, dir)
+    }
+
+    fn ray_offset(&self, from: usize, dx: isize, dy: isize) -> Ray<'_, Self::Cell> {
+        self.as_grid().ray_offset(from, dx, dy)
+    }
+
+    fn rays_from(&self, idx: usize) -> impl Iterator<Item = (Direction, Ray<'_, Self::Cell>)> {
+        self.as_grid().rays_from(idx)
+    }
+
+    fn view(&self) -> GridView<'_, Self::Cell> {
+        self.as_grid().view()
+    }
+
+    fn render<'a>(
+        &'a self,
+        cell_char: impl Fn(&Self::Cell) -> char + 'a,
+    ) -> Renderer<'a, Self::Cell> {
+        self.as_grid().render(cell_char)
+    }
+}
+
+impl<T> GridLike for Grid<T> {
+    type Cell = T;
+
+    fn as_grid(&self) -> &Grid<T> {
+        self
+    }
+
+    fn as_grid_mut(&mut self) -> &mut Grid<T> {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Labelled {
+        label: &'static str,
+        grid: Grid<u8>,
+    }
+
+    impl GridLike for Labelled {
+        type Cell = u8;
+
+        fn as_grid(&self) -> &Grid<u8> {
+            &self.grid
+        }
+
+        fn as_grid_mut(&mut self) -> &mut Grid<u8> {
+            &mut self.grid
+        }
+    }
+
+    /// A generic helper that works on any grid-like type
+    fn count_zeros<G: GridLike<Cell = u8>>(grid: &G) -> usize {
+        grid.cells().filter(|(_, cell)| **cell == 0).count()
+    }
+
+    #[test]
+    fn test_grid_like() {
+        let grid = Grid::parse("010\n200", |ch, _| Ok::<_, ()>(ch as u8 - b'0')).unwrap();
+        let mut labelled = Labelled {
+            label: "example",
+            grid: grid.clone(),
+        };
+
+        assert_eq!(count_zeros(&grid), 4);
+        assert_eq!(count_zeros(&labelled), 4);
+
+        labelled.set_xy(0, 0, 3);
+        assert_eq!(count_zeros(&labelled), 3);
+        assert_eq!(labelled.position(|cell| *cell == 2), Some(3));
+        assert_eq!(labelled.neighbors(4).count(), 3);
+        assert_eq!(labelled.num_rows(), 2);
+        assert_eq!(labelled.label, "example");
+    }
+}
@@ -0,0 +1,218 @@
+//! Loading puzzle input at runtime, rather than baking it into the binary
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable with the path of the input file to use
+pub const INPUT_ENV: &str = "AOC_INPUT";
+
+/// Where to read the puzzle input from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(InputError::Stdin)?;
+
+                Ok(input)
+            }
+            InputSource::File(path) => {
+                std::fs::read_to_string(path).map_err(|source| InputError::File {
+                    path: path.clone(),
+                    source,
+                })
+            }
+        }
+    }
+}
+
+/// The input-related command line options
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct InputArgs {
+    /// `--input <path>`, where a path of `-` means stdin
+    pub input: Option<String>,
+    /// `--example`, to use the puzzle's example input
+    pub example: bool,
+}
+
+impl InputArgs {
+    /// Parse the command line arguments, not including the program name
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, InputError> {
+        let mut parsed = InputArgs::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--example" => parsed.example = true,
+                "--input" => match args.next() {
+                    Some(path) => parsed.input = Some(path),
+                    None => return Err(InputError::MissingValue(arg)),
+                },
+                _ => match arg.strip_prefix("--input=") {
+                    Some(path) => parsed.input = Some(path.to_string()),
+                    None => return Err(InputError::UnknownArgument(arg)),
+                },
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    /// Decide where the input comes from, for the day crate in `day_dir`.
+    ///
+    /// In order of priority: `--input`, `--example` (`src/test-input.txt`),
+    /// the `AOC_INPUT` environment variable, then `src/input.txt`.
+    pub fn source(&self, day_dir: impl AsRef<Path>) -> InputSource {
+        self.source_with_env(day_dir.as_ref(), std::env::var(INPUT_ENV).ok())
+    }
+
+    fn source_with_env(&self, day_dir: &Path, env_path: Option<String>) -> InputSource {
+        match (&self.input, self.example, env_path) {
+            (Some(path), _, _) if path == "-" => InputSource::Stdin,
+            (Some(path), _, _) => InputSource::File(path.into()),
+            (None, false, Some(path)) => InputSource::File(path.into()),
+            _ => self.day_file(day_dir),
+        }
+    }
+
+    /// The day crate's own input file: `src/test-input.txt` with `--example`,
+    /// otherwise `src/input.txt`. Ignores `--input` and `AOC_INPUT`.
+    pub fn day_file(&self, day_dir: impl AsRef<Path>) -> InputSource {
+        let file = if self.example {
+            "test-input.txt"
+        } else {
+            "input.txt"
+        };
+
+        InputSource::File(day_dir.as_ref().join("src").join(file))
+    }
+}
+
+/// Read the input for the day crate in `day_dir`, based on the program's arguments
+pub fn load(day_dir: impl AsRef<Path>) -> Result<String, InputError> {
+    InputArgs::parse(std::env::args().skip(1))?
+        .source(day_dir)
+        .read()
+}
+
+/// Read the input for the current day crate, based on the program's arguments,
+/// exiting with an error message if it can't be read.
+#[macro_export]
+macro_rules! read_input {
+    () => {
+        $crate::input::load(env!("CARGO_MANIFEST_DIR")).unwrap_or_else(|e| e.exit())
+    };
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    MissingValue(String),
+    UnknownArgument(String),
+    Stdin(io::Error),
+    File { path: PathBuf, source: io::Error },
+}
+
+impl InputError {
+    /// Print the error, and exit the program
+    pub fn exit(&self) -> ! {
+        eprintln!("{self}");
+        eprintln!("Usage: [--input <path>|-] [--example]");
+
+        std::process::exit(2)
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::MissingValue(arg) => write!(f, "Missing value for {arg}"),
+            InputError::UnknownArgument(arg) => write!(f, "Unknown argument: {arg}"),
+            InputError::Stdin(e) => write!(f, "Failed to read input from stdin: {e}"),
+            InputError::File { path, source } => {
+                write!(f, "Failed to read input file {}: {source}", path.display())
+            }
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Stdin(e) | InputError::File { source: e, .. } => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Result<InputArgs, InputError> {
+        InputArgs::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(args(&[]).unwrap(), InputArgs::default());
+        assert_eq!(
+            args(&["--input", "foo.txt", "--example"]).unwrap(),
+            InputArgs {
+                input: Some("foo.txt".to_string()),
+                example: true
+            }
+        );
+        assert_eq!(args(&["--input=-"]).unwrap().input, Some("-".to_string()));
+
+        assert!(matches!(
+            args(&["--input"]),
+            Err(InputError::MissingValue(_))
+        ));
+        assert!(matches!(
+            args(&["--part", "2"]),
+            Err(InputError::UnknownArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_source_priority() {
+        let dir = Path::new("day1");
+        let env = || Some("env.txt".to_string());
+        let file = |path: &str| InputSource::File(path.into());
+
+        let source = |a: &[&str], env| args(a).unwrap().source_with_env(dir, env);
+
+        assert_eq!(source(&[], None), file("day1/src/input.txt"));
+        assert_eq!(source(&[], env()), file("env.txt"));
+        assert_eq!(
+            source(&["--example"], env()),
+            file("day1/src/test-input.txt")
+        );
+        assert_eq!(
+            source(&["--example", "--input", "x.txt"], env()),
+            file("x.txt")
+        );
+        assert_eq!(source(&["--input", "-"], env()), InputSource::Stdin);
+    }
+
+    #[test]
+    fn test_read_file() {
+        let path = std::env::temp_dir().join("aoc-shared-input-test.txt");
+        std::fs::write(&path, "1\n2\n").unwrap();
+
+        assert_eq!(InputSource::File(path.clone()).read().unwrap(), "1\n2\n");
+        std::fs::remove_file(&path).unwrap();
+
+        let err = InputSource::File(path).read().unwrap_err();
+        assert!(err.to_string().starts_with("Failed to read input file"));
+    }
+}
@@ -1,26 +1,24 @@
-pub mod grid;
+pub mod answers;
+pub mod bench;
 pub mod enums;
+pub mod grid;
+pub mod grid_like;
+pub mod input;
+pub mod location;
+pub mod parse;
+pub mod render;
+pub mod search;
+pub mod solution;
+pub mod sparse;
+pub mod view;
 
-pub use grid::*;
 pub use enums::*;
-
-#[derive(Debug, Default, Copy, Clone, Eq, Hash, PartialEq)]
-pub struct Location {
-    pub x: isize,
-    pub y: isize,
-}
-
-impl Location {
-    pub fn new(x: isize, y: isize) -> Self {
-        Location { x, y }
-    }
-
-    pub fn get_distance(self, other: Self) -> f64 {
-        let squares = (other.x - self.x).pow(2) + (other.y - self.y).pow(2);
-
-        (squares as f64).sqrt()
-    }
-}
+pub use grid::*;
+pub use grid_like::*;
+pub use location::*;
+pub use render::*;
+pub use sparse::*;
+pub use view::*;
 
 #[macro_export]
 macro_rules! deref {
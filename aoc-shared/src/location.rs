@@ -0,0 +1,159 @@
+//! Integer points on a plane
+use crate::enums::Direction;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A point, or an offset between points.
+///
+/// Like grid rows, `y` increases downwards, so `Location + Direction::Up`
+/// has a smaller `y` value.
+#[derive(Debug, Default, Copy, Clone, Eq, Hash, PartialEq)]
+pub struct Location {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Location {
+    pub fn new(x: isize, y: isize) -> Self {
+        Location { x, y }
+    }
+
+    pub fn get_distance(self, other: Self) -> f64 {
+        let squares = (other.x - self.x).pow(2) + (other.y - self.y).pow(2);
+
+        (squares as f64).sqrt()
+    }
+
+    /// Distance moving only horizontally and vertically
+    pub fn manhattan_distance(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Distance when diagonal moves are allowed, so touching points are 1 apart
+    pub fn chebyshev_distance(self, other: Self) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// The sign of each coordinate, for a step of at most 1 along each axis
+    pub fn signum(self) -> Self {
+        Location::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Move a single step (possibly diagonal) toward `other`
+    pub fn step_toward(self, other: Self) -> Self {
+        self + (other - self).signum()
+    }
+}
+
+impl Add for Location {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Location::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Location {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Location {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Location::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Location {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Location {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Location::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<isize> for Location {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Location::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Add<Direction> for Location {
+    type Output = Self;
+
+    fn add(self, rhs: Direction) -> Self::Output {
+        self + rhs.delta()
+    }
+}
+
+impl AddAssign<Direction> for Location {
+    fn add_assign(&mut self, rhs: Direction) {
+        *self = *self + rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distances() {
+        let a = Location::new(1, -2);
+        let b = Location::new(-3, 1);
+
+        assert_eq!(a.manhattan_distance(b), 7);
+        assert_eq!(a.chebyshev_distance(b), 4);
+        assert_eq!(a.chebyshev_distance(Location::new(2, -1)), 1);
+        assert_eq!(a.get_distance(Location::new(4, 2)), 5.0);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Location::new(1, -2);
+        let b = Location::new(-3, 1);
+
+        assert_eq!(a + b, Location::new(-2, -1));
+        assert_eq!(a - b, Location::new(4, -3));
+        assert_eq!(-a, Location::new(-1, 2));
+        assert_eq!(a * 3, Location::new(3, -6));
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+    }
+
+    #[test]
+    fn test_step_toward() {
+        let origin = Location::default();
+
+        assert_eq!(Location::new(5, -7).signum(), Location::new(1, -1));
+        assert_eq!(origin.step_toward(Location::new(2, 1)), Location::new(1, 1));
+        assert_eq!(
+            origin.step_toward(Location::new(0, -4)),
+            Location::new(0, -1)
+        );
+        assert_eq!(origin.step_toward(origin), origin);
+    }
+
+    #[test]
+    fn test_add_direction() {
+        let mut loc = Location::new(2, 2);
+
+        assert_eq!(loc + Direction::Up, Location::new(2, 1));
+        assert_eq!(loc + Direction::DownLeft, Location::new(1, 3));
+
+        loc += Direction::Right;
+        assert_eq!(loc, Location::new(3, 2));
+    }
+}
@@ -0,0 +1,184 @@
+//! A common error type for parsing puzzle input
+use crate::enums::ParseDirectionError;
+use crate::grid::GridParseError;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Why, and where, some puzzle input couldn't be parsed.
+///
+/// Line and column numbers start at 1. Parsers for a single line usually only
+/// know the column, so [`parse_lines`] fills in the line number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    /// The text that failed to parse
+    pub context: Option<String>,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        ParseError {
+            line: None,
+            column: None,
+            context: None,
+            message: message.into(),
+        }
+    }
+
+    /// An error for `text`, which isn't a valid `what`
+    pub fn invalid(what: &str, text: &str) -> Self {
+        ParseError::new(format!("Invalid {what}")).context(text)
+    }
+
+    pub fn context(mut self, context: impl Into<String>) -> Self {
+        self.context = Some(context.into());
+
+        self
+    }
+
+    /// Set the line number, if it isn't already known
+    pub fn line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+
+        self
+    }
+
+    /// Set the column number, if it isn't already known
+    pub fn column(mut self, column: usize) -> Self {
+        self.column.get_or_insert(column);
+
+        self
+    }
+
+    /// Shift the line number of an error from parsing part of the input,
+    /// which started at `first_line` of the whole input
+    pub fn offset_line(mut self, first_line: usize) -> Self {
+        self.line = Some(self.line.map_or(first_line, |line| line + first_line - 1));
+
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "Line {line}, column {column}: ")?,
+            (Some(line), None) => write!(f, "Line {line}: ")?,
+            (None, Some(column)) => write!(f, "Column {column}: ")?,
+            (None, None) => (),
+        }
+
+        write!(f, "{}", self.message)?;
+
+        if let Some(context) = &self.context {
+            write!(f, " {context:?}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+impl<E: fmt::Display> From<GridParseError<E>> for ParseError {
+    fn from(e: GridParseError<E>) -> Self {
+        match e {
+            GridParseError::Empty => ParseError::new("No grid rows to parse"),
+            GridParseError::RaggedRow {
+                line,
+                column,
+                expected,
+                found,
+            } => ParseError::new(format!("Expected {expected} columns, found {found}"))
+                .line(line)
+                .column(column),
+            GridParseError::InvalidCell {
+                line,
+                column,
+                error,
+            } => ParseError::new(error.to_string()).line(line).column(column),
+        }
+    }
+}
+
+impl From<ParseDirectionError> for ParseError {
+    fn from(e: ParseDirectionError) -> Self {
+        ParseError::invalid("direction", &e.0)
+    }
+}
+
+/// Parse each line of the input, adding the line number to any error
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| line.parse().map_err(|e: ParseError| e.line(i + 1)))
+        .collect()
+}
+
+/// Parse a number, or other `FromStr` type, with `what` describing it in the error
+pub fn parse_value<T: FromStr>(what: &str, text: &str) -> Result<T, ParseError> {
+    text.parse().map_err(|_| ParseError::invalid(what, text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Pair(u8, u8);
+
+    impl FromStr for Pair {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let (a, b) = s
+                .split_once(',')
+                .ok_or_else(|| ParseError::invalid("pair", s))?;
+            let b = parse_value("number", b).map_err(|e| e.column(a.len() + 2))?;
+
+            Ok(Pair(parse_value("number", a)?, b))
+        }
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(
+            parse_lines::<Pair>("1,2\n3,4"),
+            Ok(vec![Pair(1, 2), Pair(3, 4)])
+        );
+
+        let err = parse_lines::<Pair>("1,2\n3,x").unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(3)));
+        assert_eq!(err.to_string(), "Line 2, column 3: Invalid number \"x\"");
+
+        let err = parse_lines::<Pair>("1,2\n\n").unwrap_err();
+        assert_eq!(err.to_string(), "Line 2: Invalid pair \"\"");
+    }
+
+    #[test]
+    fn test_offset_line() {
+        let err = ParseError::new("Oops");
+
+        assert_eq!(err.clone().offset_line(5).line, Some(5));
+        assert_eq!(err.line(2).offset_line(5).line, Some(6));
+    }
+
+    #[test]
+    fn test_from_grid_error() {
+        let err: ParseError = crate::grid::Grid::parse("ab\nc", |ch, _| Ok::<_, String>(ch))
+            .unwrap_err()
+            .into();
+
+        assert_eq!(
+            err.to_string(),
+            "Line 2, column 2: Expected 2 columns, found 1"
+        );
+    }
+}
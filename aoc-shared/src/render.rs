@@ -0,0 +1,341 @@
+//! Text rendering of grids, for visualizing puzzle state
+use crate::grid::Grid;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+/// Terminal colours, emitted as ANSI escape codes
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    const RESET: &'static str = "\x1b[0m";
+
+    /// The escape code to switch the foreground to this colour
+    pub fn ansi_code(self) -> String {
+        match self {
+            Color::Black => "\x1b[30m".to_string(),
+            Color::Red => "\x1b[31m".to_string(),
+            Color::Green => "\x1b[32m".to_string(),
+            Color::Yellow => "\x1b[33m".to_string(),
+            Color::Blue => "\x1b[34m".to_string(),
+            Color::Magenta => "\x1b[35m".to_string(),
+            Color::Cyan => "\x1b[36m".to_string(),
+            Color::White => "\x1b[37m".to_string(),
+            Color::Rgb(r, g, b) => format!("\x1b[38;2;{r};{g};{b}m"),
+        }
+    }
+
+    /// Wrap a character in the escape codes for this colour
+    pub fn paint(self, ch: char) -> String {
+        format!("{}{}{}", self.ansi_code(), ch, Self::RESET)
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone)]
+struct Overlay {
+    ch: Option<char>,
+    color: Option<Color>,
+}
+
+type CharMapper<'a, T> = Box<dyn Fn(&T) -> char + 'a>;
+type ColorMapper<'a, T> = Box<dyn Fn(&T) -> Option<Color> + 'a>;
+
+/// Builds a text picture of a grid, one character per cell.
+///
+/// Cells are identified by a key: the index for a [`Grid`], or the `Location`
+/// for a `SparseGrid`. Overlays are keyed the same way, and take priority
+/// over the cell mappers.
+pub struct Renderer<'a, T, K = usize> {
+    rows: Vec<Vec<(K, Option<&'a T>)>>,
+    cell_char: CharMapper<'a, T>,
+    cell_color: Option<ColorMapper<'a, T>>,
+    empty: char,
+    overlays: HashMap<K, Overlay>,
+}
+
+impl<'a, T> Renderer<'a, T> {
+    pub fn new(grid: &'a Grid<T>, cell_char: impl Fn(&T) -> char + 'a) -> Self {
+        let width = grid.num_cols();
+        let rows = if width == 0 {
+            Vec::new()
+        } else {
+            grid.vec
+                .chunks(width)
+                .enumerate()
+                .map(|(row, cells)| {
+                    cells
+                        .iter()
+                        .enumerate()
+                        .map(|(col, cell)| (row * width + col, Some(cell)))
+                        .collect()
+                })
+                .collect()
+        };
+
+        Renderer::from_rows(rows, cell_char)
+    }
+}
+
+impl<'a, T, K: Copy + Eq + Hash> Renderer<'a, T, K> {
+    /// Render rows of keyed cells, where `None` is a gap with no cell in it
+    pub fn from_rows(
+        rows: Vec<Vec<(K, Option<&'a T>)>>,
+        cell_char: impl Fn(&T) -> char + 'a,
+    ) -> Self {
+        Renderer {
+            rows,
+            cell_char: Box::new(cell_char),
+            cell_color: None,
+            empty: ' ',
+            overlays: HashMap::new(),
+        }
+    }
+
+    /// Colour each cell, leaving cells uncoloured when the mapper returns `None`
+    pub fn color(mut self, cell_color: impl Fn(&T) -> Option<Color> + 'a) -> Self {
+        self.cell_color = Some(Box::new(cell_color));
+
+        self
+    }
+
+    /// The character to draw for gaps without a cell
+    pub fn empty(mut self, ch: char) -> Self {
+        self.empty = ch;
+
+        self
+    }
+
+    /// Draw `ch` in place of each of the given cells
+    pub fn highlight(self, keys: impl IntoIterator<Item = K>, ch: char) -> Self {
+        self.overlay(keys.into_iter().map(|key| (key, ch)))
+    }
+
+    /// Colour each of the given cells
+    pub fn highlight_color(mut self, keys: impl IntoIterator<Item = K>, color: Color) -> Self {
+        for key in keys {
+            self.overlays.entry(key).or_default().color = Some(color);
+        }
+
+        self
+    }
+
+    /// Draw a specific character for each cell
+    pub fn overlay(mut self, cells: impl IntoIterator<Item = (K, char)>) -> Self {
+        for (key, ch) in cells {
+            self.overlays.entry(key).or_default().ch = Some(ch);
+        }
+
+        self
+    }
+
+    fn render_cell(&self, key: K, cell: Option<&T>) -> String {
+        let overlay = self.overlays.get(&key).copied().unwrap_or_default();
+        let ch = overlay
+            .ch
+            .unwrap_or_else(|| cell.map_or(self.empty, |cell| (self.cell_char)(cell)));
+        let color = overlay.color.or_else(|| {
+            let cell_color = self.cell_color.as_ref()?;
+
+            cell_color(cell?)
+        });
+
+        match color {
+            Some(color) => color.paint(ch),
+            None => ch.to_string(),
+        }
+    }
+
+    /// Render each row of the grid as a string
+    pub fn lines(&self) -> Vec<String> {
+        self.rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|&(key, cell)| self.render_cell(key, cell))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl<'a, T, K: Copy + Eq + Hash> fmt::Display for Renderer<'a, T, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in self.lines() {
+            writeln!(f, "{}", line)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Grid<T> {
+    /// Start rendering the grid, using `cell_char` to draw each cell
+    pub fn render<'a>(&'a self, cell_char: impl Fn(&T) -> char + 'a) -> Renderer<'a, T> {
+        Renderer::new(self, cell_char)
+    }
+
+    /// Render two grids side by side, marking the cells that differ.
+    /// Returns `None` if the grids are the same.
+    pub fn diff(&self, other: &Grid<T>, cell_char: impl Fn(&T) -> char) -> Option<String>
+    where
+        T: PartialEq,
+    {
+        if self == other {
+            return None;
+        }
+
+        let left = self.render(&cell_char).lines();
+        let right = other.render(&cell_char).lines();
+        let left_width = self.num_cols().max("left".len());
+        let right_width = other.num_cols().max("right".len());
+
+        let mut out = String::new();
+        if self.num_cols() != other.num_cols() || self.num_rows() != other.num_rows() {
+            out.push_str(&format!(
+                "Grid sizes differ: {}x{} vs {}x{}\n",
+                self.num_cols(),
+                self.num_rows(),
+                other.num_cols(),
+                other.num_rows()
+            ));
+        }
+        out.push_str(&format!(
+            "{:left_width$} | {:right_width$} |\n",
+            "left", "right"
+        ));
+
+        for row in 0..left.len().max(right.len()) {
+            let l = left.get(row).map(String::as_str).unwrap_or("");
+            let r = right.get(row).map(String::as_str).unwrap_or("");
+
+            let markers: String = (0..self.num_cols().max(other.num_cols()))
+                .map(|col| {
+                    let a = self.get_xy(col, row);
+                    let b = other.get_xy(col, row);
+
+                    if a == b {
+                        ' '
+                    } else {
+                        '^'
+                    }
+                })
+                .collect();
+
+            let line = format!("{l:left_width$} | {r:right_width$} | {markers}");
+            out.push_str(line.trim_end());
+            out.push('\n');
+        }
+
+        Some(out)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self.num_cols();
+        if width == 0 {
+            return Ok(());
+        }
+
+        for row in self.vec.chunks(width) {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[macro_export]
+/// Assert that two grids are equal, showing a side-by-side diff if they are not
+macro_rules! assert_grid_eq {
+    ($left: expr, $right: expr, $cell_char: expr $(,)?) => {
+        if let Some(diff) = $left.diff(&$right, $cell_char) {
+            panic!("Grids are not equal:\n{}", diff);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_grid() -> Grid<u8> {
+        Grid::parse("012\n345\n", |ch, _| Ok::<_, ()>(ch as u8 - b'0')).unwrap()
+    }
+
+    fn to_char(n: &u8) -> char {
+        (n + b'0') as char
+    }
+
+    #[test]
+    fn test_render_lines() {
+        let grid = get_grid();
+
+        assert_eq!(grid.render(to_char).lines(), vec!["012", "345"]);
+        assert_eq!(grid.render(to_char).to_string(), "012\n345\n");
+        assert_eq!(grid.to_string(), "012\n345\n");
+    }
+
+    #[test]
+    fn test_render_overlays() {
+        let grid = get_grid();
+        let rendered = grid
+            .render(to_char)
+            .highlight([0, 4], '#')
+            .overlay([(5, '>')])
+            .lines();
+
+        assert_eq!(rendered, vec!["#12", "3#>"]);
+    }
+
+    #[test]
+    fn test_render_color() {
+        let grid = get_grid();
+        let rendered = grid
+            .render(to_char)
+            .color(|n| if *n == 1 { Some(Color::Red) } else { None })
+            .highlight_color([3], Color::Rgb(1, 2, 3))
+            .lines();
+
+        assert_eq!(rendered[0], "0\x1b[31m1\x1b[0m2");
+        assert_eq!(rendered[1], "\x1b[38;2;1;2;3m3\x1b[0m45");
+    }
+
+    #[test]
+    fn test_diff() {
+        let grid = get_grid();
+        let mut other = grid.clone();
+
+        assert_eq!(grid.diff(&other, to_char), None);
+        assert_grid_eq!(grid, other, to_char);
+
+        other.set_xy(1, 1, 9);
+        let diff = grid.diff(&other, to_char).unwrap();
+
+        assert_eq!(diff, "left | right |\n012  | 012   |\n345  | 395   |  ^\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "Grids are not equal")]
+    fn test_assert_grid_eq() {
+        let grid = get_grid();
+        let mut other = grid.clone();
+        other.set_xy(0, 0, 7);
+
+        assert_grid_eq!(grid, other, to_char);
+    }
+}
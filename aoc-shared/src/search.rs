@@ -0,0 +1,678 @@
+//! Searching any graph of states, from grid cells to abstract puzzle states
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::ops::Add;
+
+/// The cost of moving between states. The default value is zero.
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<T: Copy + Ord + Default + Add<Output = T>> Cost for T {}
+
+/// A set of states, and the moves between them
+pub trait Graph {
+    type State: Clone + Eq + Hash;
+    type Cost: Cost;
+
+    /// The states that can be reached in one move from `state`, with the cost of each move
+    fn neighbors(&self, state: &Self::State) -> impl Iterator<Item = (Self::State, Self::Cost)>;
+}
+
+/// A graph that can also be followed backwards
+pub trait ReversibleGraph: Graph {
+    /// The states that can reach `state` in one move, with the cost of each move
+    fn predecessors(&self, state: &Self::State) -> impl Iterator<Item = (Self::State, Self::Cost)>;
+}
+
+/// A reversible graph with every move turned around, to search backwards from a goal
+pub struct Reversed<'a, G>(pub &'a G);
+
+impl<G: ReversibleGraph> Graph for Reversed<'_, G> {
+    type State = G::State;
+    type Cost = G::Cost;
+
+    fn neighbors(&self, state: &G::State) -> impl Iterator<Item = (G::State, G::Cost)> {
+        self.0.predecessors(state)
+    }
+}
+
+/// A graph defined by a function from each state to its neighbors
+pub struct FnGraph<F, S> {
+    neighbors: F,
+    marker: PhantomData<fn(&S)>,
+}
+
+/// Make a graph from a function, for states that don't need a type of their own
+pub fn from_fn<F, S, C, I>(neighbors: F) -> FnGraph<F, S>
+where
+    F: Fn(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+{
+    FnGraph {
+        neighbors,
+        marker: PhantomData,
+    }
+}
+
+impl<F, S, C, I> Graph for FnGraph<F, S>
+where
+    F: Fn(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    S: Clone + Eq + Hash,
+    C: Cost,
+{
+    type State = S;
+    type Cost = C;
+
+    fn neighbors(&self, state: &S) -> impl Iterator<Item = (S, C)> {
+        (self.neighbors)(state).into_iter()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A path found by a search, from the start to the goal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Found<S, C> {
+    pub path: Vec<S>,
+    /// The total cost of the moves along the path
+    pub cost: C,
+}
+
+impl<S, C> Found<S, C> {
+    /// The number of moves along the path
+    pub fn steps(&self) -> usize {
+        self.path.len() - 1
+    }
+
+    pub fn start(&self) -> &S {
+        &self.path[0]
+    }
+
+    pub fn goal(&self) -> &S {
+        &self.path[self.path.len() - 1]
+    }
+}
+
+/// The states leading back to each state visited by a search, with the cost of reaching it
+struct Trail<S, C> {
+    came_from: HashMap<S, (Option<S>, C)>,
+}
+
+impl<S: Clone + Eq + Hash, C: Cost> Trail<S, C> {
+    fn new() -> Self {
+        Trail {
+            came_from: HashMap::new(),
+        }
+    }
+
+    fn cost(&self, state: &S) -> Option<C> {
+        self.came_from.get(state).map(|&(_, cost)| cost)
+    }
+
+    fn path_to(&self, goal: S) -> Found<S, C> {
+        let cost = self.cost(&goal).unwrap_or_default();
+        let mut path = vec![goal];
+
+        while let Some((Some(prev), _)) = self.came_from.get(path.last().unwrap()) {
+            path.push(prev.clone());
+        }
+        path.reverse();
+
+        Found { path, cost }
+    }
+}
+
+/// Breadth-first search, for the path with the fewest moves to any state where `is_goal` is true
+pub fn bfs<G: Graph>(
+    graph: &G,
+    start: G::State,
+    is_goal: impl Fn(&G::State) -> bool,
+) -> Option<Found<G::State, G::Cost>> {
+    bfs_from(graph, [start], is_goal)
+}
+
+/// Breadth-first search from several states at once, for the path with the
+/// fewest moves from whichever start is closest to a goal
+pub fn bfs_from<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::State>,
+    is_goal: impl Fn(&G::State) -> bool,
+) -> Option<Found<G::State, G::Cost>> {
+    let mut trail = Trail::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if trail.cost(&start).is_none() {
+            trail
+                .came_from
+                .insert(start.clone(), (None, G::Cost::default()));
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            return Some(trail.path_to(state));
+        }
+
+        let cost = trail.cost(&state).unwrap();
+
+        for (next, step) in graph.neighbors(&state) {
+            if trail.cost(&next).is_none() {
+                trail
+                    .came_from
+                    .insert(next.clone(), (Some(state.clone()), cost + step));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Depth-first search, for any path to a goal, which isn't necessarily the shortest
+pub fn dfs<G: Graph>(
+    graph: &G,
+    start: G::State,
+    is_goal: impl Fn(&G::State) -> bool,
+) -> Option<Found<G::State, G::Cost>> {
+    let mut trail = Trail::new();
+    let mut stack = vec![(start, None, G::Cost::default())];
+
+    while let Some((state, prev, cost)) = stack.pop() {
+        if trail.cost(&state).is_some() {
+            continue;
+        }
+        trail.came_from.insert(state.clone(), (prev, cost));
+
+        if is_goal(&state) {
+            return Some(trail.path_to(state));
+        }
+
+        for (next, step) in graph.neighbors(&state) {
+            if trail.cost(&next).is_none() {
+                stack.push((next, Some(state.clone()), cost + step));
+            }
+        }
+    }
+
+    None
+}
+
+/// Dijkstra's algorithm, for the cheapest path to a goal
+pub fn dijkstra<G: Graph>(
+    graph: &G,
+    start: G::State,
+    is_goal: impl Fn(&G::State) -> bool,
+) -> Option<Found<G::State, G::Cost>> {
+    a_star(graph, start, is_goal, |_| G::Cost::default())
+}
+
+/// A* search, for the cheapest path to a goal. The `heuristic` estimates the
+/// cost from a state to the nearest goal, and must never overestimate it.
+pub fn a_star<G: Graph>(
+    graph: &G,
+    start: G::State,
+    is_goal: impl Fn(&G::State) -> bool,
+    heuristic: impl Fn(&G::State) -> G::Cost,
+) -> Option<Found<G::State, G::Cost>> {
+    let (trail, goal) = best_first(graph, start, is_goal, heuristic);
+
+    goal.map(|goal| trail.path_to(goal))
+}
+
+/// The trail of a search, and the goal it reached if any
+type Searched<S, C> = (Trail<S, C>, Option<S>);
+
+/// Expand the state with the lowest cost so far plus `heuristic` first, until
+/// reaching a goal, which is returned with the trail of every state visited
+fn best_first<G: Graph>(
+    graph: &G,
+    start: G::State,
+    is_goal: impl Fn(&G::State) -> bool,
+    heuristic: impl Fn(&G::State) -> G::Cost,
+) -> Searched<G::State, G::Cost> {
+    let mut trail = Trail::new();
+    let mut done = HashSet::new();
+
+    // The heap holds indexes into `states`, so the states themselves don't need to be `Ord`
+    let mut states = vec![start.clone()];
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), 0))]);
+    trail.came_from.insert(start, (None, G::Cost::default()));
+
+    while let Some(Reverse((_, i))) = queue.pop() {
+        let state = states[i].clone();
+
+        if !done.insert(state.clone()) {
+            continue;
+        }
+
+        if is_goal(&state) {
+            return (trail, Some(state));
+        }
+
+        let cost = trail.cost(&state).unwrap();
+
+        for (next, step) in graph.neighbors(&state) {
+            let next_cost = cost + step;
+
+            if trail.cost(&next).is_none_or(|old| next_cost < old) {
+                trail
+                    .came_from
+                    .insert(next.clone(), (Some(state.clone()), next_cost));
+                queue.push(Reverse((next_cost + heuristic(&next), states.len())));
+                states.push(next);
+            }
+        }
+    }
+
+    (trail, None)
+}
+
+/// Breadth-first search from both ends at once, for the path with the fewest
+/// moves from `start` to `goal`. This visits far fewer states than [`bfs`]
+/// when the number of states grows quickly with distance.
+pub fn bidirectional_bfs<G: ReversibleGraph>(
+    graph: &G,
+    start: G::State,
+    goal: G::State,
+) -> Option<Found<G::State, G::Cost>> {
+    if start == goal {
+        return Some(Found {
+            path: vec![start],
+            cost: G::Cost::default(),
+        });
+    }
+
+    // Each side records the state it was reached from, and the cost of that move
+    let mut forward = HashMap::from([(start.clone(), None)]);
+    let mut backward = HashMap::from([(goal.clone(), None)]);
+    let mut forward_queue = VecDeque::from([start]);
+    let mut backward_queue = VecDeque::from([goal]);
+
+    let meeting = 'search: loop {
+        if forward_queue.is_empty() || backward_queue.is_empty() {
+            return None;
+        }
+
+        // Expand a whole layer of the smaller side at a time
+        let is_forward = forward_queue.len() <= backward_queue.len();
+        let (queue, seen, other) = if is_forward {
+            (&mut forward_queue, &mut forward, &backward)
+        } else {
+            (&mut backward_queue, &mut backward, &forward)
+        };
+
+        for _ in 0..queue.len() {
+            let state = queue.pop_front().unwrap();
+            let moves: Vec<_> = if is_forward {
+                graph.neighbors(&state).collect()
+            } else {
+                graph.predecessors(&state).collect()
+            };
+
+            for (next, step) in moves {
+                if seen.contains_key(&next) {
+                    continue;
+                }
+
+                seen.insert(next.clone(), Some((state.clone(), step)));
+
+                if other.contains_key(&next) {
+                    break 'search next;
+                }
+
+                queue.push_back(next);
+            }
+        }
+    };
+
+    let mut cost = G::Cost::default();
+    let mut path = vec![meeting.clone()];
+
+    while let Some(Some((prev, step))) = forward.get(path.last().unwrap()) {
+        cost = cost + *step;
+        path.push(prev.clone());
+    }
+    path.reverse();
+
+    while let Some(Some((next, step))) = backward.get(path.last().unwrap()) {
+        cost = cost + *step;
+        path.push(next.clone());
+    }
+
+    Some(Found { path, cost })
+}
+
+/// The cheapest cost from `start` to every state that can be reached from it
+pub fn distances<G: Graph>(graph: &G, start: G::State) -> HashMap<G::State, G::Cost> {
+    let (trail, _) = best_first(graph, start, |_| false, |_| G::Cost::default());
+
+    trail
+        .came_from
+        .into_iter()
+        .map(|(state, (_, cost))| (state, cost))
+        .collect()
+}
+
+/// The fewest moves from `start` to every state that can be reached from it,
+/// along with the state each one is first reached from on the way
+pub fn bfs_distances<G: Graph>(
+    graph: &G,
+    start: G::State,
+) -> HashMap<G::State, (usize, Option<G::State>)> {
+    let mut reached = HashMap::from([(start.clone(), (0, None))]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(state) = queue.pop_front() {
+        let moves = reached[&state].0 + 1;
+
+        for (next, _) in graph.neighbors(&state) {
+            reached.entry(next.clone()).or_insert_with(|| {
+                queue.push_back(next);
+                (moves, Some(state.clone()))
+            });
+        }
+    }
+
+    reached
+}
+
+/// The cheapest cost between every pair of the given states, where one can reach the other
+pub fn all_pairs<G: Graph>(
+    graph: &G,
+    states: &[G::State],
+) -> HashMap<(G::State, G::State), G::Cost> {
+    let mut costs = HashMap::new();
+
+    for from in states {
+        let reached = distances(graph, from.clone());
+
+        for to in states {
+            if let Some(&cost) = reached.get(to) {
+                costs.insert((from.clone(), to.clone()), cost);
+            }
+        }
+    }
+
+    costs
+}
+
+/// The strongly connected components of the part of the graph that can be
+/// reached from `states`: the largest groups where every state can reach
+/// every other. Using Tarjan's algorithm, a component is only listed after
+/// every component it can reach.
+pub fn strongly_connected<G: Graph>(
+    graph: &G,
+    states: impl IntoIterator<Item = G::State>,
+) -> Vec<Vec<G::State>> {
+    // The order each state was first visited in, and the earliest state it can
+    // reach on the stack, which is its own order when it starts a component
+    let mut order: HashMap<G::State, usize> = HashMap::new();
+    let mut low: HashMap<G::State, usize> = HashMap::new();
+    let mut stack = Vec::new();
+    let mut on_stack = HashSet::new();
+    let mut components = Vec::new();
+
+    for root in states {
+        if order.contains_key(&root) {
+            continue;
+        }
+
+        // Each state being visited, with the neighbors it has left to visit
+        let mut visiting = Vec::new();
+        let mut next = Some(root);
+
+        loop {
+            if let Some(state) = next.take() {
+                order.insert(state.clone(), order.len());
+                low.insert(state.clone(), order[&state]);
+                stack.push(state.clone());
+                on_stack.insert(state.clone());
+
+                let neighbors: Vec<_> = graph.neighbors(&state).map(|(n, _)| n).collect();
+                visiting.push((state, neighbors.into_iter()));
+            }
+
+            let Some((state, neighbors)) = visiting.last_mut() else {
+                break;
+            };
+
+            if let Some(neighbor) = neighbors.next() {
+                if !order.contains_key(&neighbor) {
+                    next = Some(neighbor);
+                } else if on_stack.contains(&neighbor) {
+                    let reached = order[&neighbor].min(low[state]);
+                    low.insert(state.clone(), reached);
+                }
+                continue;
+            }
+
+            let (state, _) = visiting.pop().unwrap();
+
+            if let Some((parent, _)) = visiting.last() {
+                let reached = low[&state].min(low[parent]);
+                low.insert(parent.clone(), reached);
+            }
+
+            if low[&state] == order[&state] {
+                let mut component = Vec::new();
+
+                loop {
+                    let member = stack.pop().unwrap();
+                    on_stack.remove(&member);
+
+                    let done = member == state;
+                    component.push(member);
+                    if done {
+                        break;
+                    }
+                }
+
+                components.push(component);
+            }
+        }
+    }
+
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Numbers up to `max`, where a move adds one or doubles
+    struct Numbers {
+        max: u32,
+    }
+
+    impl Graph for Numbers {
+        type State = u32;
+        type Cost = u32;
+
+        fn neighbors(&self, n: &u32) -> impl Iterator<Item = (u32, u32)> {
+            [n + 1, n * 2]
+                .into_iter()
+                .filter(|&next| next <= self.max)
+                .map(|next| (next, 1))
+        }
+    }
+
+    impl ReversibleGraph for Numbers {
+        fn predecessors(&self, n: &u32) -> impl Iterator<Item = (u32, u32)> {
+            let halved = n.is_multiple_of(2).then_some(n / 2);
+
+            [n.checked_sub(1), halved]
+                .into_iter()
+                .flatten()
+                .filter(|&prev| prev > 0)
+                .map(|prev| (prev, 1))
+        }
+    }
+
+    fn weighted() -> FnGraph<impl Fn(&char) -> Vec<(char, u32)>, char> {
+        from_fn(|state: &char| match state {
+            'a' => vec![('b', 1), ('c', 5)],
+            'b' => vec![('c', 1), ('d', 7)],
+            'c' => vec![('d', 2)],
+            _ => vec![],
+        })
+    }
+
+    fn is_valid(graph: &impl Graph<State = u32>, path: &[u32]) -> bool {
+        path.windows(2)
+            .all(|step| graph.neighbors(&step[0]).any(|(next, _)| next == step[1]))
+    }
+
+    #[test]
+    fn test_bfs() {
+        let graph = Numbers { max: 100 };
+        let found = bfs(&graph, 1, |&n| n == 37).unwrap();
+
+        assert_eq!(found.path, vec![1, 2, 4, 8, 9, 18, 36, 37]);
+        assert_eq!(found.steps(), 7);
+        assert_eq!(found.cost, 7);
+        assert_eq!((found.start(), found.goal()), (&1, &37));
+
+        assert_eq!(bfs(&graph, 1, |&n| n == 101), None);
+        assert_eq!(bfs_from(&graph, [1, 18], |&n| n == 37).unwrap().steps(), 2);
+    }
+
+    #[test]
+    fn test_dfs() {
+        let graph = Numbers { max: 100 };
+        let found = dfs(&graph, 1, |&n| n == 37).unwrap();
+
+        assert_eq!(found.goal(), &37);
+        assert!(is_valid(&graph, &found.path));
+        assert_eq!(dfs(&graph, 1, |&n| n == 0), None);
+    }
+
+    #[test]
+    fn test_cheapest_path() {
+        let graph = weighted();
+
+        assert_eq!(
+            bfs(&graph, 'a', |&s| s == 'd'),
+            Some(Found {
+                path: vec!['a', 'b', 'd'],
+                cost: 8
+            })
+        );
+        assert_eq!(
+            dijkstra(&graph, 'a', |&s| s == 'd'),
+            Some(Found {
+                path: vec!['a', 'b', 'c', 'd'],
+                cost: 4
+            })
+        );
+        assert_eq!(dijkstra(&graph, 'd', |&s| s == 'a'), None);
+    }
+
+    #[test]
+    fn test_a_star() {
+        // A 10x10 grid with a wall down the middle, except at the bottom
+        let grid = from_fn(|&(x, y): &(i32, i32)| {
+            [(0, 1), (1, 0), (0, -1), (-1, 0)]
+                .map(|(dx, dy)| (x + dx, y + dy))
+                .into_iter()
+                .filter(|&(x, y)| (0..10).contains(&x) && (0..10).contains(&y))
+                .filter(|&(x, y)| x != 5 || y == 9)
+                .map(|next| (next, 1u32))
+                .collect::<Vec<_>>()
+        });
+        let goal = (9, 0);
+        let manhattan = |&(x, y): &(i32, i32)| x.abs_diff(goal.0) + y.abs_diff(goal.1);
+
+        let found = a_star(&grid, (0, 0), |&s| s == goal, manhattan).unwrap();
+
+        assert_eq!(found.cost, 27);
+        assert_eq!(
+            Some(found.cost),
+            dijkstra(&grid, (0, 0), |&s| s == goal).map(|f| f.cost)
+        );
+    }
+
+    #[test]
+    fn test_bidirectional_bfs() {
+        let graph = Numbers { max: 1000 };
+
+        for goal in [1, 2, 37, 511, 999] {
+            let found = bidirectional_bfs(&graph, 1, goal).unwrap();
+            let expected = bfs(&graph, 1, |&n| n == goal).unwrap();
+
+            assert_eq!(found.steps(), expected.steps(), "Path to {goal}");
+            assert_eq!(found.cost, expected.cost);
+            assert_eq!((found.start(), found.goal()), (&1, &goal));
+            assert!(is_valid(&graph, &found.path));
+        }
+
+        assert_eq!(bidirectional_bfs(&graph, 10, 3), None);
+    }
+
+    #[test]
+    fn test_reversed() {
+        let graph = Numbers { max: 100 };
+        let found = bfs(&Reversed(&graph), 37, |&n| n == 1).unwrap();
+
+        assert_eq!(found.path, vec![37, 36, 18, 9, 8, 4, 2, 1]);
+        assert_eq!(bfs_distances(&Reversed(&graph), 37)[&1], (7, Some(2)));
+    }
+
+    #[test]
+    fn test_strongly_connected() {
+        // Two cycles, a-b-c and d-e, joined one way, and a state on its own
+        let graph = from_fn(|state: &char| {
+            let next: &[char] = match state {
+                'a' => &['b'],
+                'b' => &['c', 'd'],
+                'c' => &['a'],
+                'd' => &['e'],
+                'e' => &['d', 'f'],
+                _ => &[],
+            };
+
+            next.iter().map(|&n| (n, 1)).collect::<Vec<_>>()
+        });
+
+        let mut components = strongly_connected(&graph, ['a']);
+        for component in &mut components {
+            component.sort();
+        }
+
+        assert_eq!(
+            components,
+            vec![vec!['f'], vec!['d', 'e'], vec!['a', 'b', 'c']]
+        );
+        assert_eq!(strongly_connected(&graph, ['e', 'z']).len(), 3);
+
+        // A long chain shouldn't overflow the stack
+        let chain = Numbers { max: 10_000 };
+        assert_eq!(strongly_connected(&chain, [1]).len(), 10_000);
+    }
+
+    #[test]
+    fn test_distances() {
+        let graph = weighted();
+
+        assert_eq!(
+            distances(&graph, 'b'),
+            HashMap::from([('b', 0), ('c', 1), ('d', 3)])
+        );
+
+        // Moves, rather than costs, and the way back to the start
+        let reached = bfs_distances(&graph, 'a');
+        assert_eq!(reached[&'d'], (2, Some('b')));
+        assert_eq!(reached[&'a'], (0, None));
+        assert_eq!(reached.len(), 4);
+
+        let pairs = all_pairs(&graph, &['a', 'c', 'd']);
+        assert_eq!(pairs.get(&('a', 'd')), Some(&4));
+        assert_eq!(pairs.get(&('c', 'd')), Some(&2));
+        assert_eq!(pairs.get(&('d', 'd')), Some(&0));
+        assert_eq!(pairs.get(&('d', 'a')), None);
+        assert_eq!(pairs.len(), 6);
+    }
+}
@@ -0,0 +1,234 @@
+//! A common interface for each day's puzzle, so they can all be run the same way
+use crate::bench::{self, BenchOptions, BenchResult};
+use crate::parse::ParseError;
+use std::fmt::{self, Display};
+use std::time::{Duration, Instant};
+
+/// A day's puzzle, parsed from its input and ready to be solved
+pub trait Solution: Sized {
+    /// The day of the puzzle, from 1 to 25
+    const DAY: u8;
+
+    /// The title of the puzzle
+    const TITLE: &'static str;
+
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self, ParseError>;
+
+    fn part1(&self) -> Self::Part1;
+
+    fn part2(&self) -> Self::Part2;
+}
+
+/// The answer to one part of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub part: u8,
+    pub answer: String,
+    pub time: Duration,
+}
+
+/// The results of solving a day's puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub day: u8,
+    pub title: &'static str,
+    pub parse_time: Duration,
+    pub answers: Vec<Answer>,
+}
+
+/// Parse the input, then solve the given part, or both parts if `part` is `None`
+pub fn solve<S: Solution>(input: &str, part: Option<u8>) -> Result<Report, ParseError> {
+    let start = Instant::now();
+    let solution = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let mut answers = Vec::new();
+
+    if part.is_none() || part == Some(1) {
+        answers.push(time_part(1, || solution.part1()));
+    }
+
+    if part.is_none() || part == Some(2) {
+        answers.push(time_part(2, || solution.part2()));
+    }
+
+    Ok(Report {
+        day: S::DAY,
+        title: S::TITLE,
+        parse_time,
+        answers,
+    })
+}
+
+fn time_part<A: Display>(part: u8, f: impl FnOnce() -> A) -> Answer {
+    let start = Instant::now();
+    let answer = f().to_string();
+
+    Answer {
+        part,
+        answer,
+        time: start.elapsed(),
+    }
+}
+
+/// A type-erased [`Solution`], so that different days can be kept in one list
+#[derive(Debug, Clone, Copy)]
+pub struct Solver {
+    pub day: u8,
+    pub title: &'static str,
+    pub solve: fn(&str, Option<u8>) -> Result<Report, ParseError>,
+    pub bench: fn(&str, &BenchOptions) -> Result<BenchResult, ParseError>,
+}
+
+impl Solver {
+    pub const fn of<S: Solution>() -> Self {
+        Solver {
+            day: S::DAY,
+            title: S::TITLE,
+            solve: solve::<S>,
+            bench: bench::bench::<S>,
+        }
+    }
+}
+
+/// Formats reports as a table, one row per answer.
+///
+/// Answers spanning several lines continue on the following rows, in the answer column.
+#[derive(Debug, Clone, Default)]
+pub struct Table(pub Vec<Report>);
+
+impl Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let title_width = self
+            .0
+            .iter()
+            .map(|report| report.title.len())
+            .max()
+            .unwrap_or(0)
+            .max("Title".len());
+
+        writeln!(
+            f,
+            "{:>3}  {:title_width$}  {:>4}  {:>10}  Answer",
+            "Day", "Title", "Part", "Time"
+        )?;
+
+        for report in &self.0 {
+            for answer in &report.answers {
+                let mut lines = answer.answer.lines();
+                let first = lines.next().unwrap_or("");
+
+                writeln!(
+                    f,
+                    "{:>3}  {:title_width$}  {:>4}  {:>10}  {}",
+                    report.day,
+                    report.title,
+                    answer.part,
+                    format!("{:.1?}", answer.time),
+                    first
+                )?;
+
+                for line in lines {
+                    writeln!(f, "{:indent$}{}", "", line, indent = title_width + 25)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Solve both parts of a day's puzzle, and print the results,
+/// exiting with an error message if the input is invalid
+pub fn run<S: Solution>(input: &str) {
+    match solve::<S>(input, None) {
+        Ok(report) => print!("{}", Table(vec![report])),
+        Err(e) => {
+            eprintln!("Invalid input for day {}: {e}", S::DAY);
+            std::process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_value;
+
+    struct Sum(Vec<u32>);
+
+    impl Solution for Sum {
+        const DAY: u8 = 1;
+        const TITLE: &'static str = "Sums";
+
+        type Part1 = u32;
+        type Part2 = String;
+
+        fn parse(input: &str) -> Result<Self, ParseError> {
+            input
+                .lines()
+                .map(|line| parse_value("number", line))
+                .collect::<Result<_, _>>()
+                .map(Sum)
+        }
+
+        fn part1(&self) -> u32 {
+            self.0.iter().sum()
+        }
+
+        fn part2(&self) -> String {
+            self.0
+                .iter()
+                .map(|n| n.to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+    }
+
+    fn without_times(report: &mut Report) {
+        report.parse_time = Duration::ZERO;
+        report
+            .answers
+            .iter_mut()
+            .for_each(|a| a.time = Duration::ZERO);
+    }
+
+    #[test]
+    fn test_solve() {
+        let report = solve::<Sum>("1\n2\n3", None).unwrap();
+
+        assert_eq!(report.day, 1);
+        assert_eq!(report.answers.len(), 2);
+        assert_eq!(report.answers[0].answer, "6");
+        assert_eq!(report.answers[1].answer, "1\n2\n3");
+
+        let solver = Solver::of::<Sum>();
+        let report = (solver.solve)("1\n2\n3", Some(2)).unwrap();
+        assert_eq!(report.answers.len(), 1);
+        assert_eq!(report.answers[0].part, 2);
+
+        let err = solve::<Sum>("1\nx", None).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid number \"x\"");
+    }
+
+    #[test]
+    fn test_table() {
+        let mut report = solve::<Sum>("1\n2", None).unwrap();
+        without_times(&mut report);
+
+        assert_eq!(
+            Table(vec![report]).to_string(),
+            [
+                "Day  Title  Part        Time  Answer",
+                "  1  Sums      1       0.0ns  3",
+                "  1  Sums      2       0.0ns  1",
+                "                              2",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+}
@@ -0,0 +1,279 @@
+//! An unbounded grid, for puzzles where the area of interest isn't known up front
+use crate::grid::{DIAGONAL, ORTHOGONAL, SURROUNDING};
+use crate::render::Renderer;
+use crate::Location;
+use std::collections::HashMap;
+
+/// A grid of cells keyed by `Location`, which can grow in any direction,
+/// including into negative coordinates.
+///
+/// The bounding box of the occupied cells is kept up to date as cells are
+/// added and removed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Location, T>,
+    min: Location,
+    max: Location,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            min: Location::default(),
+            max: Location::default(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The top-left and bottom-right corners of the occupied area, inclusive
+    pub fn bounds(&self) -> Option<(Location, Location)> {
+        if self.is_empty() {
+            None
+        } else {
+            Some((self.min, self.max))
+        }
+    }
+
+    pub fn num_cols(&self) -> usize {
+        self.bounds()
+            .map_or(0, |(min, max)| (max.x - min.x) as usize + 1)
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.bounds()
+            .map_or(0, |(min, max)| (max.y - min.y) as usize + 1)
+    }
+
+    pub fn contains(&self, loc: Location) -> bool {
+        self.cells.contains_key(&loc)
+    }
+
+    pub fn get(&self, loc: Location) -> Option<&T> {
+        self.cells.get(&loc)
+    }
+
+    pub fn get_mut(&mut self, loc: Location) -> Option<&mut T> {
+        self.cells.get_mut(&loc)
+    }
+
+    /// Set the value at `loc`, returning the previous value, if there was one
+    pub fn insert(&mut self, loc: Location, value: T) -> Option<T> {
+        self.grow(loc);
+
+        self.cells.insert(loc, value)
+    }
+
+    /// Get the value at `loc`, adding one with `f` if the cell is empty
+    pub fn get_or_insert_with(&mut self, loc: Location, f: impl FnOnce() -> T) -> &mut T {
+        self.grow(loc);
+
+        self.cells.entry(loc).or_insert_with(f)
+    }
+
+    pub fn remove(&mut self, loc: Location) -> Option<T> {
+        let value = self.cells.remove(&loc)?;
+
+        let on_edge = loc.x == self.min.x
+            || loc.x == self.max.x
+            || loc.y == self.min.y
+            || loc.y == self.max.y;
+
+        if on_edge {
+            self.recalculate_bounds();
+        }
+
+        Some(value)
+    }
+
+    fn grow(&mut self, loc: Location) {
+        if self.is_empty() {
+            self.min = loc;
+            self.max = loc;
+            return;
+        }
+
+        self.min = Location::new(self.min.x.min(loc.x), self.min.y.min(loc.y));
+        self.max = Location::new(self.max.x.max(loc.x), self.max.y.max(loc.y));
+    }
+
+    fn recalculate_bounds(&mut self) {
+        let mut locations = self.cells.keys();
+
+        if let Some(&first) = locations.next() {
+            let (min, max) = locations.fold((first, first), |(min, max), loc| {
+                (
+                    Location::new(min.x.min(loc.x), min.y.min(loc.y)),
+                    Location::new(max.x.max(loc.x), max.y.max(loc.y)),
+                )
+            });
+
+            self.min = min;
+            self.max = max;
+        }
+    }
+
+    /// Iterate over the occupied cells, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Location, &T)> {
+        self.cells.iter().map(|(loc, cell)| (*loc, cell))
+    }
+
+    pub fn locations(&self) -> impl Iterator<Item = Location> + '_ {
+        self.cells.keys().copied()
+    }
+
+    /// Iterate over the occupied cells above, below, left, and right of `loc`
+    pub fn neighbors(&self, loc: Location) -> impl Iterator<Item = (Location, &T)> {
+        self.stencil_neighbors(loc, &ORTHOGONAL)
+    }
+
+    /// Iterate over the occupied cells touching the corners of `loc`
+    pub fn diagonal_neighbors(&self, loc: Location) -> impl Iterator<Item = (Location, &T)> {
+        self.stencil_neighbors(loc, &DIAGONAL)
+    }
+
+    /// Iterate over the occupied cells of the eight surrounding `loc`
+    pub fn all_neighbors(&self, loc: Location) -> impl Iterator<Item = (Location, &T)> {
+        self.stencil_neighbors(loc, &SURROUNDING)
+    }
+
+    /// Iterate over the occupied cells at each `(dx, dy)` offset from `loc`
+    pub fn stencil_neighbors<'a>(
+        &'a self,
+        loc: Location,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (Location, &'a T)> {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let neighbor = Location::new(loc.x + dx, loc.y + dy);
+
+            self.get(neighbor).map(|cell| (neighbor, cell))
+        })
+    }
+
+    /// Start rendering the occupied area, using `cell_char` to draw each cell.
+    /// Rows are drawn from the smallest y value to the largest.
+    pub fn render<'a>(&'a self, cell_char: impl Fn(&T) -> char + 'a) -> Renderer<'a, T, Location> {
+        let rows = match self.bounds() {
+            Some((min, max)) => (min.y..=max.y)
+                .map(|y| {
+                    (min.x..=max.x)
+                        .map(|x| {
+                            let loc = Location::new(x, y);
+
+                            (loc, self.get(loc))
+                        })
+                        .collect()
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+
+        Renderer::from_rows(rows, cell_char).empty('.')
+    }
+}
+
+impl<T> FromIterator<(Location, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Location, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+
+        for (loc, value) in iter {
+            grid.insert(loc, value);
+        }
+
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_grid() -> SparseGrid<char> {
+        [
+            (Location::new(-2, -1), 'a'),
+            (Location::new(0, 0), 'b'),
+            (Location::new(1, 0), 'c'),
+            (Location::new(0, 1), 'd'),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = get_grid();
+
+        assert_eq!(
+            grid.bounds(),
+            Some((Location::new(-2, -1), Location::new(1, 1)))
+        );
+        assert_eq!(grid.num_cols(), 4);
+        assert_eq!(grid.num_rows(), 3);
+
+        grid.insert(Location::new(5, -3), 'e');
+        assert_eq!(
+            grid.bounds(),
+            Some((Location::new(-2, -3), Location::new(5, 1)))
+        );
+
+        grid.remove(Location::new(5, -3));
+        grid.remove(Location::new(-2, -1));
+        assert_eq!(
+            grid.bounds(),
+            Some((Location::new(0, 0), Location::new(1, 1)))
+        );
+
+        assert_eq!(SparseGrid::<char>::new().bounds(), None);
+    }
+
+    #[test]
+    fn test_get_or_insert_with() {
+        let mut grid: SparseGrid<usize> = SparseGrid::new();
+
+        *grid.get_or_insert_with(Location::new(-1, 0), || 0) += 1;
+        *grid.get_or_insert_with(Location::new(-1, 0), || 0) += 1;
+
+        assert_eq!(grid.get(Location::new(-1, 0)), Some(&2));
+        assert_eq!(grid.len(), 1);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = get_grid();
+        let mut neighbors: Vec<char> = grid
+            .neighbors(Location::new(0, 0))
+            .map(|(_, ch)| *ch)
+            .collect();
+        neighbors.sort();
+
+        assert_eq!(neighbors, vec!['c', 'd']);
+        assert_eq!(grid.diagonal_neighbors(Location::new(1, 0)).count(), 1);
+        assert_eq!(grid.all_neighbors(Location::new(-1, 0)).count(), 3);
+    }
+
+    #[test]
+    fn test_render() {
+        let grid = get_grid();
+
+        assert_eq!(grid.render(|ch| *ch).lines(), vec!["a...", "..bc", "..d."]);
+        assert_eq!(
+            grid.render(|_| '#')
+                .highlight([Location::new(-1, 1)], 's')
+                .to_string(),
+            "#...\n..##\n.s#.\n"
+        );
+    }
+}
@@ -0,0 +1,348 @@
+//! Zero-copy rotated, flipped, and windowed views of a grid
+use crate::grid::Grid;
+
+/// A read-only view of a rectangular part of a [`Grid`], possibly rotated or flipped.
+///
+/// View coordinates map onto the underlying grid with a fixed origin and a step
+/// for each axis, so views can be stacked without copying any cells. Only complete
+/// rows of the underlying grid are visible.
+#[derive(Debug)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    origin: (isize, isize),
+    x_step: (isize, isize),
+    y_step: (isize, isize),
+    width: usize,
+    height: usize,
+}
+
+impl<'a, T> Clone for GridView<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for GridView<'a, T> {}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn new(grid: &'a Grid<T>) -> Self {
+        GridView {
+            grid,
+            origin: (0, 0),
+            x_step: (1, 0),
+            y_step: (0, 1),
+            width: grid.num_cols(),
+            height: grid.num_rows(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Convert a view coordinate to an x,y coordinate of the underlying grid
+    fn base_xy(&self, x: usize, y: usize) -> (usize, usize) {
+        let (x, y) = (x as isize, y as isize);
+        let base_x = self.origin.0 + self.x_step.0 * x + self.y_step.0 * y;
+        let base_y = self.origin.1 + self.x_step.1 * x + self.y_step.1 * y;
+
+        (base_x as usize, base_y as usize)
+    }
+
+    /// Get the index in the underlying grid of the view's x,y coordinate
+    pub fn idx(&self, x: usize, y: usize) -> Option<usize> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        let (x, y) = self.base_xy(x, y);
+
+        Some(self.grid.xy_idx(x, y))
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&'a T> {
+        self.idx(x, y).and_then(|idx| self.grid.get(idx))
+    }
+
+    /// Iterate over a row of the view, as `(index, cell)` pairs of the underlying grid
+    pub fn row(&self, y: usize) -> impl Iterator<Item = (usize, &'a T)> + 'a {
+        let view = *self;
+        let width = if y < self.height { self.width } else { 0 };
+
+        (0..width).map(move |x| {
+            let idx = view.idx(x, y).unwrap();
+
+            (idx, &view.grid.vec[idx])
+        })
+    }
+
+    /// Iterate over each row of the view, from top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, &'a T)> + 'a> + 'a {
+        let view = *self;
+
+        (0..self.height).map(move |y| view.row(y))
+    }
+
+    /// Iterate over every cell of the view, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &'a T)> + 'a {
+        self.rows().flatten()
+    }
+
+    /// Indexes of the underlying grid, in the order they appear in the view
+    pub fn indexes(&self) -> impl Iterator<Item = usize> + 'a {
+        self.iter().map(|(idx, _)| idx)
+    }
+
+    /// Copy the cells of the view into a new grid
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let mut grid = Grid::new(self.width);
+        grid.vec = self.iter().map(|(_, cell)| cell.clone()).collect();
+
+        grid
+    }
+
+    /// Swap rows and columns
+    pub fn transpose(&self) -> Self {
+        GridView {
+            x_step: self.y_step,
+            y_step: self.x_step,
+            width: self.height,
+            height: self.width,
+            ..*self
+        }
+    }
+
+    /// Mirror left to right
+    pub fn flip_horizontal(&self) -> Self {
+        if self.width == 0 {
+            return *self;
+        }
+
+        GridView {
+            origin: self.signed_base_xy(self.width - 1, 0),
+            x_step: (-self.x_step.0, -self.x_step.1),
+            ..*self
+        }
+    }
+
+    /// Mirror top to bottom
+    pub fn flip_vertical(&self) -> Self {
+        if self.height == 0 {
+            return *self;
+        }
+
+        GridView {
+            origin: self.signed_base_xy(0, self.height - 1),
+            y_step: (-self.y_step.0, -self.y_step.1),
+            ..*self
+        }
+    }
+
+    /// Rotate a quarter turn clockwise
+    pub fn rotate_90(&self) -> Self {
+        self.transpose().flip_horizontal()
+    }
+
+    /// Rotate a half turn
+    pub fn rotate_180(&self) -> Self {
+        self.flip_horizontal().flip_vertical()
+    }
+
+    /// Rotate a quarter turn anticlockwise
+    pub fn rotate_270(&self) -> Self {
+        self.transpose().flip_vertical()
+    }
+
+    /// A rectangular window of the view, with its top-left corner at x,y.
+    /// Returns `None` if the window does not fit within the view.
+    pub fn window(&self, x: usize, y: usize, width: usize, height: usize) -> Option<Self> {
+        if x + width > self.width || y + height > self.height {
+            return None;
+        }
+
+        Some(GridView {
+            origin: self.signed_base_xy(x, y),
+            width,
+            height,
+            ..*self
+        })
+    }
+
+    fn signed_base_xy(&self, x: usize, y: usize) -> (isize, isize) {
+        let (x, y) = self.base_xy(x, y);
+
+        (x as isize, y as isize)
+    }
+}
+
+impl<T> Grid<T> {
+    /// A view of the whole grid, to rotate, flip, or window without copying
+    pub fn view(&self) -> GridView<'_, T> {
+        GridView::new(self)
+    }
+
+    /// A view of a rectangle of the grid, with its top-left corner at x,y
+    pub fn window(
+        &self,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    ) -> Option<GridView<'_, T>> {
+        self.view().window(x, y, width, height)
+    }
+
+    /// Rearrange the cells of the grid into the order of a view.
+    ///
+    /// Panics if the grid ends in a partial row, as its cells have nowhere
+    /// to go in the rearranged grid.
+    fn into_view_order(self, view: impl Fn(GridView<'_, T>) -> GridView<'_, T>) -> Self {
+        assert_eq!(
+            self.num_rows() * self.num_cols(),
+            self.len(),
+            "Can't rearrange a grid that ends in a partial row"
+        );
+
+        let (order, width): (Vec<usize>, usize) = {
+            let view = view(self.view());
+
+            (view.indexes().collect(), view.width())
+        };
+
+        let mut cells: Vec<Option<T>> = self.vec.into_iter().map(Some).collect();
+        let mut grid = Grid::new(width);
+        grid.vec = order
+            .into_iter()
+            .map(|idx| cells[idx].take().unwrap())
+            .collect();
+
+        grid
+    }
+
+    pub fn transposed(self) -> Self {
+        self.into_view_order(|view| view.transpose())
+    }
+
+    pub fn rotated_90(self) -> Self {
+        self.into_view_order(|view| view.rotate_90())
+    }
+
+    pub fn rotated_180(self) -> Self {
+        self.into_view_order(|view| view.rotate_180())
+    }
+
+    pub fn rotated_270(self) -> Self {
+        self.into_view_order(|view| view.rotate_270())
+    }
+
+    pub fn flipped_horizontal(self) -> Self {
+        self.into_view_order(|view| view.flip_horizontal())
+    }
+
+    pub fn flipped_vertical(self) -> Self {
+        self.into_view_order(|view| view.flip_vertical())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_grid() -> Grid<char> {
+        // abc
+        // def
+        Grid::parse("abc\ndef", |ch, _| Ok::<_, ()>(ch)).unwrap()
+    }
+
+    fn view_rows(view: GridView<'_, char>) -> Vec<String> {
+        view.rows()
+            .map(|row| row.map(|(_, ch)| ch).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_transforms() {
+        let grid = get_grid();
+        let view = grid.view();
+
+        assert_eq!(view_rows(view), vec!["abc", "def"]);
+        assert_eq!(view_rows(view.transpose()), vec!["ad", "be", "cf"]);
+        assert_eq!(view_rows(view.rotate_90()), vec!["da", "eb", "fc"]);
+        assert_eq!(view_rows(view.rotate_180()), vec!["fed", "cba"]);
+        assert_eq!(view_rows(view.rotate_270()), vec!["cf", "be", "ad"]);
+        assert_eq!(view_rows(view.flip_horizontal()), vec!["cba", "fed"]);
+        assert_eq!(view_rows(view.flip_vertical()), vec!["def", "abc"]);
+        assert_eq!(
+            view_rows(view.rotate_90().rotate_90().rotate_90().rotate_90()),
+            view_rows(view)
+        );
+    }
+
+    #[test]
+    fn test_view_indexes() {
+        let grid = get_grid();
+
+        assert_eq!(
+            grid.view().rotate_90().indexes().collect::<Vec<_>>(),
+            vec![3, 0, 4, 1, 5, 2]
+        );
+        assert_eq!(grid.view().rotate_90().get(1, 0), Some(&'a'));
+        assert_eq!(grid.view().rotate_90().idx(2, 0), None);
+    }
+
+    #[test]
+    fn test_window() {
+        let grid = Grid::parse("abcd\nefgh\nijkl", |ch, _| Ok::<_, ()>(ch)).unwrap();
+        let window = grid.window(1, 1, 2, 2).unwrap();
+
+        assert_eq!(view_rows(window), vec!["fg", "jk"]);
+        assert_eq!(view_rows(window.rotate_90()), vec!["jf", "kg"]);
+        assert_eq!(
+            view_rows(grid.view().rotate_90().window(0, 1, 3, 2).unwrap()),
+            vec!["jfb", "kgc"]
+        );
+        assert!(grid.window(3, 0, 2, 1).is_none());
+        assert!(grid.window(0, 0, 4, 3).is_some());
+    }
+
+    #[test]
+    fn test_owning_transforms() {
+        let rotated = get_grid().rotated_90();
+
+        assert_eq!(rotated.num_cols(), 2);
+        assert_eq!(rotated.vec, vec!['d', 'a', 'e', 'b', 'f', 'c']);
+        assert_eq!(
+            get_grid().transposed(),
+            get_grid().view().transpose().to_grid()
+        );
+        assert_eq!(get_grid().rotated_180().rotated_180(), get_grid());
+        assert_eq!(
+            get_grid().rotated_270(),
+            get_grid().rotated_90().rotated_180()
+        );
+        assert_eq!(
+            get_grid().flipped_horizontal().vec,
+            "cbafed".chars().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            get_grid().flipped_vertical().vec,
+            "defabc".chars().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "partial row")]
+    fn test_owning_transforms_reject_partial_rows() {
+        let mut grid = get_grid();
+        grid.vec.push('g');
+
+        grid.rotated_90();
+    }
+}
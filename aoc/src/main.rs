@@ -0,0 +1,414 @@
+//! Runs, benchmarks, or checks the answers of the puzzle solutions for any or all days
+use aoc_shared::answers::{answers_path, Answers, AnswersError, Check, CheckTable};
+use aoc_shared::bench::{Baseline, BenchOptions, BenchReport, BenchResult};
+use aoc_shared::input::{InputArgs, InputSource};
+use aoc_shared::parse::ParseError;
+use aoc_shared::solution::{Report, Solver, Table};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+const SOLVERS: &[Solver] = &[
+    Solver::of::<day1::Day1>(),
+    Solver::of::<day2::Day2>(),
+    Solver::of::<day3::Day3>(),
+    Solver::of::<day4::Day4>(),
+    Solver::of::<day5::Day5>(),
+    Solver::of::<day6::Day6>(),
+    Solver::of::<day7::Day7>(),
+    Solver::of::<day8::Day8>(),
+    Solver::of::<day9::Day9>(),
+    Solver::of::<day10::Day10>(),
+    Solver::of::<day11::Day11>(),
+    Solver::of::<day12::Day12>(),
+];
+
+const USAGE: &str = "\
+Usage: aoc run <day|all> [--part <1|2>] [--input <path>|-] [--example]
+       aoc bench <day|all> [--warmup <n>] [--samples <n>] [--time-limit <secs>]
+                 [--json|--compare <path>] [--input <path>|-] [--example]
+       aoc verify <day|all> [--part <1|2>]
+       aoc record <day|all> [--part <1|2>]";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Days {
+    All,
+    One(u8),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Command {
+    /// Solve the puzzles, or just one part of them
+    Run { part: Option<u8> },
+    /// Time the puzzles, and report as a table or JSON, or against the JSON
+    /// report of an earlier run
+    Bench {
+        options: BenchOptions,
+        json: bool,
+        compare: Option<PathBuf>,
+    },
+    /// Solve the puzzles, and compare with the recorded answers
+    Verify { part: Option<u8> },
+    /// Solve the puzzles, and record the answers as correct
+    Record { part: Option<u8> },
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Args {
+    command: Command,
+    days: Days,
+    input: InputArgs,
+}
+
+/// The value following an option, parsed
+fn option_value<T: FromStr>(
+    option: &str,
+    args: &mut impl Iterator<Item = String>,
+) -> Result<T, String> {
+    let value = args
+        .next()
+        .ok_or_else(|| format!("Missing value for {option}"))?;
+
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value for {option}: {value}"))
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+    let mut args = args.into_iter();
+
+    let mut command = match args.next().as_deref() {
+        Some("run") => Command::Run { part: None },
+        Some("bench") => Command::Bench {
+            options: BenchOptions::default(),
+            json: false,
+            compare: None,
+        },
+        Some("verify") => Command::Verify { part: None },
+        Some("record") => Command::Record { part: None },
+        Some(command) => return Err(format!("Unknown command: {command}")),
+        None => return Err("Missing command".to_string()),
+    };
+
+    let days = match args.next().as_deref() {
+        Some("all") => Days::All,
+        Some(day) => match day.parse::<u8>() {
+            Ok(day) if SOLVERS.iter().any(|s| s.day == day) => Days::One(day),
+            _ => return Err(format!("No solution for day: {day}")),
+        },
+        None => return Err("Missing day".to_string()),
+    };
+
+    let mut input_args = Vec::new();
+
+    while let Some(arg) = args.next() {
+        match (&mut command, arg.as_str()) {
+            (
+                Command::Run { part } | Command::Verify { part } | Command::Record { part },
+                "--part",
+            ) => {
+                *part = match option_value(&arg, &mut args)? {
+                    p @ (1 | 2) => Some(p),
+                    p => return Err(format!("Invalid part: {p}")),
+                };
+            }
+            (Command::Bench { options, .. }, "--warmup") => {
+                options.warmup = option_value(&arg, &mut args)?;
+            }
+            (Command::Bench { options, .. }, "--samples") => {
+                options.samples = option_value(&arg, &mut args)?;
+            }
+            (Command::Bench { options, .. }, "--time-limit") => {
+                let secs: f64 = option_value(&arg, &mut args)?;
+                options.time_limit = Duration::try_from_secs_f64(secs)
+                    .map_err(|_| format!("Invalid value for {arg}: {secs}"))?;
+            }
+            (Command::Bench { json, .. }, "--json") => *json = true,
+            (Command::Bench { compare, .. }, "--compare") => {
+                *compare = Some(option_value(&arg, &mut args)?);
+            }
+            _ => input_args.push(arg),
+        }
+    }
+
+    let input = InputArgs::parse(input_args).map_err(|e| e.to_string())?;
+
+    if let Command::Bench {
+        json: true,
+        compare: Some(_),
+        ..
+    } = command
+    {
+        return Err("--json and --compare can't be used together".to_string());
+    }
+
+    if days == Days::All && input.input.is_some() {
+        return Err("--input can only be used when running a single day".to_string());
+    }
+
+    let answers_input = matches!(command, Command::Verify { .. } | Command::Record { .. });
+    if answers_input && input != InputArgs::default() {
+        return Err("Answers are only recorded for each day's src/input.txt".to_string());
+    }
+
+    Ok(Args {
+        command,
+        days,
+        input,
+    })
+}
+
+/// The directory of the crate for the given day
+fn day_dir(day: u8) -> PathBuf {
+    let workspace = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+
+    workspace.parent().unwrap().join(format!("day{day}"))
+}
+
+/// Where to read a day's input from. Verifying and recording always use
+/// the day's own input file, ignoring `AOC_INPUT` as well as `--input`.
+fn input_source(day: u8, args: &Args) -> InputSource {
+    let dir = day_dir(day);
+    let answers_input = matches!(
+        args.command,
+        Command::Verify { .. } | Command::Record { .. }
+    );
+
+    if args.days == Days::All || answers_input {
+        args.input.day_file(dir)
+    } else {
+        args.input.source(dir)
+    }
+}
+
+fn read_input(solver: &Solver, args: &Args) -> Result<String, String> {
+    input_source(solver.day, args)
+        .read()
+        .map_err(|e| format!("Day {}: {e}", solver.day))
+}
+
+/// The medians of an earlier run, from its JSON report
+fn read_baseline(path: &Path) -> Result<Baseline, String> {
+    let json = std::fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+
+    json.parse()
+        .map_err(|e| format!("{}: Invalid report: {e}", path.display()))
+}
+
+/// What a command produced for the days it was run on
+#[derive(Debug, Default)]
+struct Output {
+    reports: Vec<Report>,
+    results: Vec<BenchResult>,
+    checks: Vec<Check>,
+}
+
+fn run_day(solver: &Solver, args: &Args, output: &mut Output) -> Result<(), String> {
+    let input = read_input(solver, args)?;
+    let dir = day_dir(solver.day);
+
+    let invalid_input = |e: ParseError| format!("Day {}: Invalid input: {e}", solver.day);
+    let answers_error = |e: AnswersError| format!("Day {}: {e}", solver.day);
+    let solve = |part| (solver.solve)(&input, part).map_err(invalid_input);
+
+    match args.command {
+        Command::Run { part } => output.reports.push(solve(part)?),
+        Command::Bench { options, .. } => {
+            let result = (solver.bench)(&input, &options).map_err(invalid_input)?;
+
+            output.results.push(result);
+        }
+        Command::Verify { part } => {
+            let report = solve(part)?;
+            let answers = Answers::load(&dir).map_err(answers_error)?;
+
+            output.checks.extend(answers.check(&report));
+        }
+        Command::Record { part } => {
+            let report = solve(part)?;
+            let mut answers = Answers::load(&dir).map_err(answers_error)?;
+            answers.record(&report);
+            answers.save(&dir).map_err(answers_error)?;
+
+            eprintln!("Recorded answers in {}", answers_path(&dir).display());
+            output.reports.push(report);
+        }
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let args = parse_args(std::env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        eprintln!("{USAGE}");
+        std::process::exit(2);
+    });
+
+    // Check the earlier run can be read before spending time on this one
+    let baseline = match &args.command {
+        Command::Bench {
+            compare: Some(path),
+            ..
+        } => Some(read_baseline(path).unwrap_or_else(|e| {
+            eprintln!("{e}");
+            std::process::exit(1);
+        })),
+        _ => None,
+    };
+
+    let mut output = Output::default();
+    let mut failed = false;
+
+    for solver in SOLVERS {
+        if args.days != Days::All && args.days != Days::One(solver.day) {
+            continue;
+        }
+
+        if let Err(e) = run_day(solver, &args, &mut output) {
+            eprintln!("{e}");
+            failed = true;
+        }
+    }
+
+    match args.command {
+        Command::Run { .. } | Command::Record { .. } => print!("{}", Table(output.reports)),
+        Command::Bench { options, json, .. } => {
+            let report = BenchReport {
+                options,
+                results: output.results,
+            };
+
+            if let Some(baseline) = &baseline {
+                print!("{}", report.compare(baseline));
+            } else if json {
+                print!("{}", report.to_json());
+            } else {
+                print!("{report}");
+            }
+        }
+        Command::Verify { .. } => {
+            failed |= output.checks.iter().any(Check::is_wrong);
+            print!("{}", CheckTable(output.checks));
+        }
+    }
+
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &str) -> Result<Args, String> {
+        parse_args(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            args("run 9 --part 2 --example"),
+            Ok(Args {
+                command: Command::Run { part: Some(2) },
+                days: Days::One(9),
+                input: InputArgs {
+                    input: None,
+                    example: true
+                },
+            })
+        );
+        assert_eq!(args("run all").unwrap().days, Days::All);
+        assert_eq!(
+            args("run 7 --input foo.txt").unwrap().input.input,
+            Some("foo.txt".to_string())
+        );
+
+        assert!(args("").is_err());
+        assert!(args("walk 7").is_err());
+        assert!(args("run 26").is_err());
+        assert!(args("run 7 --part 3").is_err());
+        assert!(args("run all --input foo.txt").is_err());
+        assert!(args("run 7 --verbose").is_err());
+    }
+
+    #[test]
+    fn test_parse_bench_args() {
+        assert_eq!(
+            args("bench all --samples 50 --warmup 0 --time-limit 0.5 --json").map(|a| a.command),
+            Ok(Command::Bench {
+                options: BenchOptions {
+                    warmup: 0,
+                    samples: 50,
+                    time_limit: Duration::from_millis(500),
+                },
+                json: true,
+                compare: None,
+            })
+        );
+        assert_eq!(
+            args("bench 3 --example").map(|a| a.command),
+            Ok(Command::Bench {
+                options: BenchOptions::default(),
+                json: false,
+                compare: None,
+            })
+        );
+        assert_eq!(
+            args("bench all --compare before.json").map(|a| a.command),
+            Ok(Command::Bench {
+                options: BenchOptions::default(),
+                json: false,
+                compare: Some(PathBuf::from("before.json")),
+            })
+        );
+
+        assert!(args("bench 3 --samples").is_err());
+        assert!(args("bench 3 --samples many").is_err());
+        assert!(args("bench 3 --time-limit -1").is_err());
+        assert!(args("bench 3 --part 1").is_err());
+        assert!(args("run 3 --json").is_err());
+        assert!(args("bench 3 --compare").is_err());
+        assert!(args("bench 3 --compare before.json --json").is_err());
+        assert!(args("run 3 --compare before.json").is_err());
+    }
+
+    #[test]
+    fn test_parse_answers_args() {
+        assert_eq!(
+            args("verify all").map(|a| a.command),
+            Ok(Command::Verify { part: None })
+        );
+        assert_eq!(
+            args("record 4 --part 2").map(|a| a.command),
+            Ok(Command::Record { part: Some(2) })
+        );
+
+        assert!(args("verify all --example").is_err());
+        assert!(args("record 4 --input foo.txt").is_err());
+        assert!(args("verify 4 --json").is_err());
+    }
+
+    #[test]
+    fn test_answers_ignore_input_env() {
+        std::env::set_var(aoc_shared::input::INPUT_ENV, "elsewhere.txt");
+
+        let own_input = InputSource::File(day_dir(4).join("src").join("input.txt"));
+        assert_eq!(input_source(4, &args("verify 4").unwrap()), own_input);
+        assert_eq!(input_source(4, &args("record 4").unwrap()), own_input);
+        assert_eq!(
+            input_source(4, &args("run 4").unwrap()),
+            InputSource::File("elsewhere.txt".into())
+        );
+
+        std::env::remove_var(aoc_shared::input::INPUT_ENV);
+    }
+
+    #[test]
+    fn test_solvers_in_order() {
+        for (i, solver) in SOLVERS.iter().enumerate() {
+            assert_eq!(solver.day as usize, i + 1);
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use aoc_shared::parse::{parse_value, ParseError};
+use aoc_shared::solution::Solution;
+
+fn get_elves(raw: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    let mut elves = vec![Vec::new()];
+
+    for (i, line) in raw.lines().enumerate() {
+        if line.is_empty() {
+            elves.push(Vec::new());
+            continue;
+        }
+
+        let calories = parse_value("calorie count", line).map_err(|e| e.line(i + 1))?;
+        elves.last_mut().unwrap().push(calories);
+    }
+
+    Ok(elves)
+}
+
+fn get_elf_totals(elves: &[Vec<u32>]) -> Vec<u32> {
+    elves.iter().map(|elf| elf.iter().sum()).collect()
+}
+
+// ----------------------------------------------------------------------------
+
+/// The total calories carried by each elf, from most to least
+pub struct Day1 {
+    totals: Vec<u32>,
+}
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Calorie Counting";
+
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let elves = get_elves(input)?;
+
+        let mut totals: Vec<u32> = get_elf_totals(&elves);
+        totals.sort();
+        totals.reverse();
+
+        Ok(Day1 { totals })
+    }
+
+    /// Most calories for one elf
+    fn part1(&self) -> u32 {
+        self.totals[0]
+    }
+
+    /// Calories for the top three elves
+    fn part2(&self) -> u32 {
+        self.totals.iter().take(3).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let day = Day1::parse(include_str!("test-input.txt")).unwrap();
+
+        assert_eq!(day.part1(), 24000);
+        assert_eq!(day.part2(), 45000);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let err = Day1::parse("1000\n\n2000\n3OOO\n").err().unwrap();
+
+        assert_eq!(err.to_string(), "Line 4: Invalid calorie count \"3OOO\"");
+    }
+}
@@ -0,0 +1,253 @@
+use aoc_shared::grid::Grid;
+use aoc_shared::parse::{parse_lines, parse_value, ParseError};
+use aoc_shared::solution::Solution;
+use std::cmp;
+use std::str::FromStr;
+
+#[derive(Debug)]
+enum Instruction {
+    Addx(isize),
+    Noop,
+}
+use Instruction::*;
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = line.split_ascii_whitespace().collect();
+
+        match parts[..] {
+            ["addx", value] => Ok(Addx(parse_value("value", value).map_err(|e| e.column(6))?)),
+            ["noop"] => Ok(Noop),
+            _ => Err(ParseError::invalid("instruction", line)),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct Cpu {
+    x: isize,
+}
+
+impl Cpu {
+    pub fn new() -> Self {
+        Cpu { x: 1 }
+    }
+
+    fn noop(&self) -> usize {
+        1
+    }
+
+    fn add_x(&mut self, i: isize) -> usize {
+        self.x += i;
+
+        2
+    }
+
+    pub fn get_x(&self) -> isize {
+        self.x
+    }
+
+    pub fn run(&mut self, command: Instruction) -> usize {
+        match command {
+            Addx(i) => self.add_x(i),
+            Noop => self.noop(),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+#[derive(Debug, Default, Copy, Clone)]
+enum Pixel {
+    Lit,
+    #[default]
+    Dark,
+}
+use Pixel::*;
+
+// -----------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct Crt {
+    pixels: Grid<Pixel>,
+}
+
+impl Crt {
+    fn new() -> Self {
+        let mut pixels = Grid::new(40);
+        pixels.vec = vec![Dark; 240];
+
+        Self { pixels }
+    }
+
+    fn get_lines(&self) -> Vec<String> {
+        self.pixels
+            .render(|p| match p {
+                Lit => '#',
+                Dark => '.',
+            })
+            .lines()
+    }
+
+    pub fn draw_pixel(&mut self, cycle: usize, x: isize) {
+        let line_x = x % 40;
+        let line_cycle = (cycle - 1) % 40;
+
+        let sprite_s = cmp::max(line_x - 1, 0);
+        let sprite_e = cmp::min(line_x + 1, 39);
+        let sprite: Vec<usize> = ((sprite_s as usize)..=(sprite_e as usize)).collect();
+
+        if sprite.contains(&(line_cycle)) {
+            self.pixels.vec[cycle - 1] = Lit;
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct CycleCounter {
+    cpu: Cpu,
+    crt: Crt,
+    log: Vec<isize>,
+    cycle: usize,
+}
+
+impl CycleCounter {
+    pub fn new() -> Self {
+        let mut cc = Self {
+            cpu: Cpu::new(),
+            crt: Crt::new(),
+            log: vec![1, 1],
+            cycle: 1,
+        };
+
+        // Do first cycle
+        cc.crt.draw_pixel(cc.cycle, cc.cpu.get_x());
+
+        cc
+    }
+
+    fn run_instruction(&mut self, instruction: Instruction) {
+        let x = self.cpu.get_x();
+        let cycles = self.cpu.run(instruction);
+
+        for _ in 0..(cycles - 1) {
+            self.add_cycle(x);
+        }
+
+        self.add_cycle(self.cpu.get_x());
+    }
+
+    fn add_cycle(&mut self, x: isize) {
+        self.cycle += 1;
+        self.crt.draw_pixel(self.cycle, x);
+        self.log.push(x);
+    }
+
+    pub fn get_signal_strength(&self, cycle: usize) -> usize {
+        let x = self.log.get(cycle).unwrap();
+
+        (*x as usize) * cycle
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+/// The Cpu's register log, and the picture drawn on the Crt
+pub struct Day10 {
+    cc: CycleCounter,
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Cathode-Ray Tube";
+
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut cc = CycleCounter::new();
+        parse_lines(input)?
+            .into_iter()
+            .for_each(|instruction| cc.run_instruction(instruction));
+
+        Ok(Day10 { cc })
+    }
+
+    /// Sum of the signal strengths during the interesting cycles
+    fn part1(&self) -> usize {
+        [20usize, 60, 100, 140, 180, 220]
+            .into_iter()
+            .map(|n| self.cc.get_signal_strength(n))
+            .sum()
+    }
+
+    /// The display output
+    fn part2(&self) -> String {
+        self.cc.crt.get_lines().join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> &'static str {
+        include_str!("test-input.txt")
+    }
+
+    #[test]
+    fn test_get_signal_strength() {
+        let mut cc = CycleCounter::new();
+
+        parse_lines(get_test_data())
+            .unwrap()
+            .into_iter()
+            .for_each(|instruction| cc.run_instruction(instruction));
+
+        assert_eq!(cc.get_signal_strength(20), 420);
+        assert_eq!(cc.get_signal_strength(60), 1140);
+        assert_eq!(cc.get_signal_strength(100), 1800);
+        assert_eq!(cc.get_signal_strength(140), 2940);
+        assert_eq!(cc.get_signal_strength(180), 2880);
+        assert_eq!(cc.get_signal_strength(220), 3960);
+    }
+
+    #[test]
+    fn test_crt_get_lines() {
+        let file_str = include_str!("test-input.txt");
+        let mut cc = CycleCounter::new();
+
+        parse_lines(file_str)
+            .unwrap()
+            .into_iter()
+            .for_each(|instruction| cc.run_instruction(instruction));
+
+        let actual = cc.crt.get_lines();
+
+        let expected = vec![
+            "##..##..##..##..##..##..##..##..##..##..".to_string(),
+            "###...###...###...###...###...###...###.".to_string(),
+            "####....####....####....####....####....".to_string(),
+            "#####.....#####.....#####.....#####.....".to_string(),
+            "######......######......######......####".to_string(),
+            "#######.......#######.......#######.....".to_string(),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let err = Day10::parse("noop\naddx 3\nsubx 2").err().unwrap();
+        assert_eq!(err.to_string(), "Line 3: Invalid instruction \"subx 2\"");
+
+        let err = Day10::parse("addx three").err().unwrap();
+        assert_eq!(err.to_string(), "Line 1, column 6: Invalid value \"three\"");
+    }
+}
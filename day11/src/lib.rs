@@ -0,0 +1,531 @@
+use aoc_shared::parse::{parse_value, ParseError};
+use aoc_shared::solution::Solution;
+use std::collections::VecDeque;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum WorryType {
+    Normal,
+    Extra,
+}
+
+/// A worry level, with the arithmetic the monkeys need. The operations
+/// return `None` if the result doesn't fit in the type.
+pub trait Worry: Clone + fmt::Debug {
+    fn from_usize(level: usize) -> Self;
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+    fn div(&self, divisor: usize) -> Self;
+    /// The remainder after dividing by `divisor`
+    fn rem(&self, divisor: usize) -> usize;
+}
+
+macro_rules! impl_worry {
+    ($($t: ty),*) => {
+        $(
+            impl Worry for $t {
+                fn from_usize(level: usize) -> Self {
+                    level as $t
+                }
+
+                fn checked_add(&self, other: &Self) -> Option<Self> {
+                    <$t>::checked_add(*self, *other)
+                }
+
+                fn checked_mul(&self, other: &Self) -> Option<Self> {
+                    <$t>::checked_mul(*self, *other)
+                }
+
+                fn div(&self, divisor: usize) -> Self {
+                    self / divisor as $t
+                }
+
+                fn rem(&self, divisor: usize) -> usize {
+                    (self % divisor as $t) as usize
+                }
+            }
+        )*
+    };
+}
+
+impl_worry!(usize, u128);
+
+/// Exact worry levels, however large they get
+#[cfg(feature = "bigint")]
+impl Worry for num_bigint::BigUint {
+    fn from_usize(level: usize) -> Self {
+        level.into()
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Some(self * other)
+    }
+
+    fn div(&self, divisor: usize) -> Self {
+        self / divisor
+    }
+
+    fn rem(&self, divisor: usize) -> usize {
+        usize::try_from(self % divisor).unwrap()
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+enum Operand {
+    Old,
+    Literal(usize),
+}
+
+#[derive(Debug, Clone)]
+struct Operation {
+    operator: char,
+    operand: Operand,
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    /// Parse the right hand side of the operation, after "new = old "
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let operator = match s.split_once(' ') {
+            Some(("+", _)) => '+',
+            Some(("*", _)) => '*',
+            _ => return Err(ParseError::invalid("operation", s)),
+        };
+
+        let operand = match &s[2..] {
+            "old" => Operand::Old,
+            operand => Operand::Literal(parse_value("operand", operand)?),
+        };
+
+        Ok(Operation { operator, operand })
+    }
+}
+
+impl Operation {
+    /// The new worry level, or `None` if it overflows
+    #[inline(always)]
+    fn run<W: Worry>(&self, old: &W) -> Option<W> {
+        let other = match self.operand {
+            Operand::Old => old.clone(),
+            Operand::Literal(other) => W::from_usize(other),
+        };
+
+        match self.operator {
+            '+' => old.checked_add(&other),
+            '*' => old.checked_mul(&other),
+            _ => panic!("Invalid operator"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey<W = usize> {
+    items: VecDeque<W>,
+    operation: Operation,
+    test: usize,
+    pass_monkey: usize,
+    fail_monkey: usize,
+    inspection_count: usize,
+    inspection_worry: WorryType,
+}
+
+/// The text after `prefix` on line `idx` of a monkey's behavior
+fn field<'a>(lines: &[&'a str], idx: usize, prefix: &str) -> Result<&'a str, ParseError> {
+    lines
+        .get(idx)
+        .and_then(|line| line.trim().strip_prefix(prefix))
+        .map(str::trim)
+        .ok_or_else(|| ParseError::new(format!("Expected {prefix:?}")).line(idx + 1))
+}
+
+impl Monkey {
+    pub fn from_behavior(raw: &str, inspection_worry: WorryType) -> Result<Self, ParseError> {
+        let lines: Vec<&str> = raw.lines().collect();
+
+        field(&lines, 0, "Monkey ")?;
+
+        let items = field(&lines, 1, "Starting items:")?
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(|item| parse_value("worry level", item).map_err(|e| e.line(2)))
+            .collect::<Result<VecDeque<usize>, _>>()?;
+
+        let operation = field(&lines, 2, "Operation: new = old ")?
+            .parse()
+            .map_err(|e: ParseError| e.line(3))?;
+
+        let test = parse_value("divisor", field(&lines, 3, "Test: divisible by ")?)
+            .and_then(|test| match test {
+                0 => Err(ParseError::new("Divisor can't be zero")),
+                _ => Ok(test),
+            })
+            .map_err(|e| e.line(4))?;
+
+        let monkey = |idx, prefix| {
+            parse_value("monkey number", field(&lines, idx, prefix)?).map_err(|e| e.line(idx + 1))
+        };
+
+        Ok(Monkey {
+            items,
+            operation,
+            test,
+            pass_monkey: monkey(4, "If true: throw to monkey ")?,
+            fail_monkey: monkey(5, "If false: throw to monkey ")?,
+            inspection_count: 0,
+            inspection_worry,
+        })
+    }
+
+    /// The same monkey, holding its items as another type of worry level
+    fn with_worry<W: Worry>(&self) -> Monkey<W> {
+        Monkey {
+            items: self.items.iter().map(|&item| W::from_usize(item)).collect(),
+            operation: self.operation.clone(),
+            test: self.test,
+            pass_monkey: self.pass_monkey,
+            fail_monkey: self.fail_monkey,
+            inspection_count: self.inspection_count,
+            inspection_worry: self.inspection_worry,
+        }
+    }
+}
+
+impl<W: Worry> Monkey<W> {
+    #[inline(always)]
+    fn run_test(&self, item: &W) -> usize {
+        if item.rem(self.test) == 0 {
+            self.pass_monkey
+        } else {
+            self.fail_monkey
+        }
+    }
+
+    /// The monkey to throw the item to, and its new worry level, or `None` if
+    /// the worry level overflows. With a `divisor_product`, the worry level is
+    /// kept small by only tracking its remainder.
+    #[inline(always)]
+    pub fn inspect(&mut self, mut item: W, divisor_product: Option<usize>) -> Option<(usize, W)> {
+        self.inspection_count += 1;
+
+        let worry = if self.inspection_worry == WorryType::Normal {
+            self.operation.run(&item)?.div(3)
+        } else {
+            // This is the whole key to keeping the number small enough to be practical.
+            // I don't really understand it, but I was sick of this not being finished,
+            // so I based the fix on
+            // https://fasterthanli.me/series/advent-of-code-2022/part-11
+            if let Some(divisor_product) = divisor_product {
+                item = W::from_usize(item.rem(divisor_product));
+            }
+            self.operation.run(&item)?
+        };
+
+        let new_monkey = self.run_test(&worry);
+
+        Some((new_monkey, worry))
+    }
+
+    #[inline(always)]
+    pub fn catch(&mut self, item: W) {
+        self.items.push_back(item);
+    }
+}
+
+/// A worry level got too big for its type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow {
+    pub round: usize,
+    pub monkey: usize,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Worry level overflowed in round {}, when monkey {} inspected an item",
+            self.round, self.monkey
+        )
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+#[derive(Debug, Clone)]
+pub struct MonkeyGame<W = usize> {
+    monkeys: Vec<Monkey<W>>,
+    rounds: usize,
+    /// Only tracking the worry levels modulo this product keeps them small with
+    /// extra worry, or `None` to keep the exact levels
+    divisor_product: Option<usize>,
+}
+
+impl MonkeyGame {
+    pub fn from_file_str(file_str: &str, inspection_worry: WorryType) -> Result<Self, ParseError> {
+        let mut monkeys = Vec::new();
+        let mut first_line = 1;
+
+        for behavior in file_str.split("\n\n") {
+            let monkey = Monkey::from_behavior(behavior, inspection_worry)
+                .map_err(|e| e.offset_line(first_line))?;
+            first_line += behavior.lines().count() + 1;
+
+            monkeys.push(monkey);
+        }
+
+        if let Some(m) = monkeys
+            .iter()
+            .position(|m| m.pass_monkey.max(m.fail_monkey) >= monkeys.len())
+        {
+            return Err(ParseError::new(format!(
+                "Monkey {m} throws to a monkey that doesn't exist"
+            )));
+        }
+
+        // The magic divisor for getting the result with normal integer sizes
+        let divisor_product = match inspection_worry {
+            WorryType::Normal => None,
+            WorryType::Extra => Some(monkeys.iter().map(|m| m.test).product::<usize>()),
+        };
+
+        Ok(Self {
+            monkeys,
+            rounds: 0,
+            divisor_product,
+        })
+    }
+
+    /// The same game, tracking the exact worry levels as `W` rather than their
+    /// remainders, to check that the remainders give the same results
+    pub fn exact<W: Worry>(&self) -> MonkeyGame<W> {
+        MonkeyGame {
+            monkeys: self.monkeys.iter().map(Monkey::with_worry).collect(),
+            rounds: self.rounds,
+            divisor_product: None,
+        }
+    }
+}
+
+impl<W: Worry> MonkeyGame<W> {
+    fn throw(&mut self, item: W, to: usize) {
+        self.monkeys[to].catch(item);
+    }
+
+    /// Panics if a worry level overflows
+    #[inline(always)]
+    pub fn do_rounds(&mut self, rounds: usize) -> &Self {
+        self.try_rounds(rounds).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Play the rounds, stopping at the first worry level that overflows
+    pub fn try_rounds(&mut self, rounds: usize) -> Result<&Self, Overflow> {
+        for _ in 0..rounds {
+            self.rounds += 1;
+
+            for m in 0..self.monkeys.len() {
+                while let Some(worry) = self.monkeys[m].items.pop_front() {
+                    let (monkey_idx, worry) = self.monkeys[m]
+                        .inspect(worry, self.divisor_product)
+                        .ok_or(Overflow {
+                            round: self.rounds,
+                            monkey: m,
+                        })?;
+                    self.throw(worry, monkey_idx);
+                }
+            }
+        }
+
+        Ok(self)
+    }
+
+    pub fn get_inspection_counts(&self) -> Vec<usize> {
+        let mut counts: Vec<usize> = self.monkeys.iter().map(|m| m.inspection_count).collect();
+
+        counts.sort();
+
+        counts.into_iter().rev().collect()
+    }
+
+    pub fn get_monkey_business(&self) -> usize {
+        let inspections = self.get_inspection_counts();
+
+        inspections[0] * inspections[1]
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// The starting state of the monkeys, for each level of worry
+pub struct Day11 {
+    normal: MonkeyGame,
+    extra: MonkeyGame,
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Monkey in the Middle";
+
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day11 {
+            normal: MonkeyGame::from_file_str(input, WorryType::Normal)?,
+            extra: MonkeyGame::from_file_str(input, WorryType::Extra)?,
+        })
+    }
+
+    /// Monkey business after 20 rounds
+    fn part1(&self) -> usize {
+        self.normal.clone().do_rounds(20).get_monkey_business()
+    }
+
+    /// Monkey business after 10,000 rounds, without worry relief
+    fn part2(&self) -> usize {
+        self.extra.clone().do_rounds(10_000).get_monkey_business()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> &'static str {
+        include_str!("test-input.txt")
+    }
+
+    #[test]
+    fn monkey_round() {
+        let mut game = MonkeyGame::from_file_str(get_test_data(), WorryType::Normal).unwrap();
+        game.do_rounds(1);
+
+        assert_eq!(game.monkeys[0].items, VecDeque::from([20, 23, 27, 26]));
+        assert_eq!(
+            game.monkeys[1].items,
+            VecDeque::from([2080, 25, 167, 207, 401, 1046])
+        );
+        assert_eq!(game.monkeys[2].items, VecDeque::new());
+        assert_eq!(game.monkeys[3].items, VecDeque::new());
+    }
+
+    #[test]
+    fn monkey_20_rounds() {
+        let mut game = MonkeyGame::from_file_str(get_test_data(), WorryType::Normal).unwrap();
+        game.do_rounds(20);
+
+        assert_eq!(game.monkeys[0].inspection_count, 101);
+        assert_eq!(game.monkeys[3].inspection_count, 105);
+        assert_eq!(game.get_monkey_business(), 10605);
+    }
+
+    #[test]
+    fn monkey_20_rounds_extra_worry() {
+        let mut game = MonkeyGame::from_file_str(get_test_data(), WorryType::Extra).unwrap();
+        game.do_rounds(20);
+
+        assert_eq!(game.monkeys[0].inspection_count, 99);
+        assert_eq!(game.monkeys[3].inspection_count, 103);
+        assert_eq!(game.get_monkey_business(), 10197);
+    }
+
+    #[test]
+    fn monkey_1000_rounds_extra_worry() {
+        let mut game = MonkeyGame::from_file_str(get_test_data(), WorryType::Extra).unwrap();
+        game.do_rounds(1000);
+
+        assert_eq!(game.monkeys[0].inspection_count, 5204);
+        assert_eq!(game.monkeys[3].inspection_count, 5192);
+    }
+
+    #[test]
+    fn monkey_10_000_rounds_extra_worry() {
+        let mut game = MonkeyGame::from_file_str(get_test_data(), WorryType::Extra).unwrap();
+        game.do_rounds(10_000);
+
+        assert_eq!(game.monkeys[0].inspection_count, 52166);
+        assert_eq!(game.monkeys[3].inspection_count, 52013);
+        assert_eq!(game.get_monkey_business(), 2713310158);
+    }
+
+    /// Play the same rounds keeping the remainders and the exact worry levels,
+    /// and check the remainders give the same results
+    fn assert_matches_exact<W: Worry>(worry: WorryType, rounds: usize) {
+        let mut modular = MonkeyGame::from_file_str(get_test_data(), worry).unwrap();
+        let mut exact = modular.exact::<W>();
+        modular.do_rounds(rounds);
+        exact.do_rounds(rounds);
+
+        assert_eq!(
+            modular.get_inspection_counts(),
+            exact.get_inspection_counts(),
+            "{worry:?} worry, {rounds} rounds"
+        );
+
+        let product = 23 * 19 * 13 * 17;
+        for (modular, exact) in modular.monkeys.iter().zip(&exact.monkeys) {
+            let exact: Vec<usize> = exact.items.iter().map(|item| item.rem(product)).collect();
+            let modular: Vec<usize> = modular.items.iter().map(|item| item % product).collect();
+
+            assert_eq!(modular, exact);
+        }
+    }
+
+    #[test]
+    fn modular_matches_exact() {
+        // Exact worry levels fit in a u128 for the first 30 rounds
+        for rounds in [1, 5, 20, 30] {
+            assert_matches_exact::<u128>(WorryType::Extra, rounds);
+        }
+        assert_matches_exact::<u128>(WorryType::Normal, 20);
+    }
+
+    #[test]
+    fn exact_overflow() {
+        let game = MonkeyGame::from_file_str(get_test_data(), WorryType::Extra).unwrap();
+        let err = game.exact::<u128>().try_rounds(1000).err().unwrap();
+
+        assert_eq!(
+            err,
+            Overflow {
+                round: 31,
+                monkey: 0
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "Worry level overflowed in round 31, when monkey 0 inspected an item"
+        );
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn modular_matches_bigint() {
+        for rounds in [1, 31, 100] {
+            assert_matches_exact::<num_bigint::BigUint>(WorryType::Extra, rounds);
+        }
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let input = get_test_data().replace("new = old * 19", "new = old / 19");
+        let err = Day11::parse(&input).err().unwrap();
+        assert_eq!(err.to_string(), "Line 3: Invalid operation \"/ 19\"");
+
+        let input = get_test_data().replace("79, 60, 97", "79, 6O, 97");
+        let err = Day11::parse(&input).err().unwrap();
+        assert_eq!(err.to_string(), "Line 16: Invalid worry level \"6O\"");
+
+        let input = get_test_data().replace("throw to monkey 3", "throw to monkey 4");
+        let err = Day11::parse(&input).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Monkey 0 throws to a monkey that doesn't exist"
+        );
+    }
+}
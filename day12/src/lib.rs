@@ -1,10 +1,14 @@
 mod node;
-use core::fmt;
-use node::Node;
+pub use node::Node;
 
 use aoc_shared::enums::Direction;
-use aoc_shared::grid::Grid as BaseGrid;
-use aoc_shared::impl_grid_newtype;
+use aoc_shared::grid::Grid;
+use aoc_shared::grid_like::GridLike;
+use aoc_shared::parse::ParseError;
+use aoc_shared::render::{Color, Renderer};
+use aoc_shared::search::{self, Found, Graph, ReversibleGraph};
+use aoc_shared::solution::Solution;
+use std::fmt;
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 enum CellType {
@@ -16,13 +20,12 @@ enum CellType {
 #[derive(Debug, Clone)]
 struct Cell {
     kind: CellType,
-    idx: usize,
     coord: (usize, usize),
 }
 
 impl Cell {
-    pub fn new(kind: CellType, idx: usize, coord: (usize, usize)) -> Self {
-        Cell { kind, idx, coord }
+    pub fn new(kind: CellType, coord: (usize, usize)) -> Self {
+        Cell { kind, coord }
     }
 
     pub fn get_height(&self) -> u8 {
@@ -32,95 +35,68 @@ impl Cell {
             CellType::Waypoint(c) => c,
         }
     }
-}
-
-// ----------------------------------------------------------------------------
-
-pub struct Grid<T>(BaseGrid<T>);
-impl_grid_newtype!(Grid, BaseGrid<Cell>, Cell);
 
-impl Grid<Cell> {
-    pub fn from_file_str(file_str: &str) -> Self {
-        let first_line = file_str.lines().next().unwrap();
-        let width = first_line.len();
+    /// A colour from blue at the lowest elevation to red at the highest
+    pub fn heat_color(&self) -> Color {
+        let heat = (self.get_height() as u32 * 255 / 25) as u8;
 
-        let mut grid = Grid::new(width);
-        let mut idx = 0usize;
-
-        for c in file_str.chars() {
-            let kind = match c {
-                'S' => CellType::Start,
-                'E' => CellType::End,
-                'a'..='z' => CellType::Waypoint(c as u8 - b'a'),
-                '\r' | '\n' => continue,
-                _ => panic!("Invalid character: {c}"),
-            };
-
-            let (x, y) = (idx % width, idx / width);
-            let cell = Cell::new(kind, idx, (x, y));
+        Color::Rgb(heat, 64, 255 - heat)
+    }
+}
 
-            grid.vec.push(cell);
+/// Which moves between neighboring cells are allowed, and what they cost
+#[derive(Debug, Clone, Copy)]
+pub struct ClimbingRules {
+    /// The most the elevation can rise in one move
+    pub max_ascent: u8,
+    /// The most the elevation can drop in one move
+    pub max_descent: u8,
+    /// The cost of moving from one elevation to another
+    pub step_cost: fn(from: u8, to: u8) -> usize,
+}
 
-            idx += 1;
+/// The puzzle's rules: climb at most one higher, descend any amount, and
+/// every move costs one step
+impl Default for ClimbingRules {
+    fn default() -> Self {
+        ClimbingRules {
+            max_ascent: 1,
+            max_descent: u8::MAX,
+            step_cost: |_, _| 1,
         }
+    }
+}
 
-        grid
+impl ClimbingRules {
+    pub fn allows(&self, from: u8, to: u8) -> bool {
+        to <= from.saturating_add(self.max_ascent) && from <= to.saturating_add(self.max_descent)
     }
 
-    fn find_pos(&self, value: CellType) -> Option<usize> {
-        self.vec.iter().position(|item| item.kind == value)
+    pub fn cost(&self, from: u8, to: u8) -> usize {
+        (self.step_cost)(from, to)
     }
+}
 
-    pub fn print(&self) {
-        for r in 0usize..self.num_rows() {
-            let range = self.row_first_idx(r)..=self.row_last_idx(r);
-            let line: String = self.vec[range]
-                .iter()
-                .map(|n| (n.get_height() + b'a') as char)
-                .collect();
+// ----------------------------------------------------------------------------
 
-            println!("{}", line);
-        }
-    }
+trait HeightMap: GridLike<Cell = Cell> {
+    fn from_file_str(file_str: &str) -> Result<Self, ParseError>
+    where
+        Self: Sized;
 
-    fn get_index_for_move(&self, from: usize, dir: Direction) -> Option<usize> {
-        let (mut x, mut y) = self.idx_xy(from);
+    fn find_pos(&self, value: CellType) -> Option<usize> {
+        self.position(|item| item.kind == value)
+    }
 
-        match dir {
-            Direction::Up => {
-                if y >= 1 {
-                    y -= 1;
-                } else {
-                    return None;
-                }
-            }
-            Direction::Down => {
-                if y < self.num_rows() - 1 {
-                    y += 1;
-                } else {
-                    return None;
-                }
-            }
-            Direction::Left => {
-                if x >= 1 {
-                    x -= 1;
-                } else {
-                    return None;
-                }
-            }
-            Direction::Right => {
-                if x < self.num_cols() - 1 {
-                    x += 1;
-                } else {
-                    return None;
-                }
-            }
-        };
+    fn render_heights(&self) -> Renderer<'_, Cell> {
+        self.render(|n| (n.get_height() + b'a') as char)
+    }
 
-        Some(self.xy_idx(x, y))
+    fn print(&self) {
+        print!("{}", self.render_heights());
     }
 
-    fn is_valid_move(&self, start: usize, end: usize) -> bool {
+    fn is_valid_move(&self, rules: &ClimbingRules, start: usize, end: usize) -> bool {
         // Is the item within the grid?
         let start_char = self.get(start);
         let end_char = self.get(end);
@@ -130,9 +106,7 @@ impl Grid<Cell> {
 
         let start_char = start_char.unwrap();
         let end_char = end_char.unwrap();
-        let start_elevation = start_char.get_height();
-        let end_elevation = end_char.get_height();
-        if (end_elevation < start_elevation) || end_elevation.abs_diff(start_elevation) > 1 {
+        if !rules.allows(start_char.get_height(), end_char.get_height()) {
             return false;
         }
 
@@ -145,116 +119,510 @@ impl Grid<Cell> {
     }
 
     fn find_moves(&self, start: usize) -> Vec<usize> {
-        [
-            Direction::Up,
-            Direction::Down,
-            Direction::Left,
-            Direction::Right,
-        ]
-        .into_iter()
-        .filter_map(|d| self.get_index_for_move(start, d))
-        .collect()
-    }
-
-    fn find_valid_moves(&self, start: usize) -> Vec<usize> {
+        self.neighbors(start).map(|(idx, _)| idx).collect()
+    }
+
+    fn find_valid_moves(&self, rules: &ClimbingRules, start: usize) -> Vec<usize> {
         self.find_moves(start)
             .into_iter()
-            .filter(|m| self.is_valid_move(start, *m))
+            .filter(|m| self.is_valid_move(rules, start, *m))
+            .collect()
+    }
+
+    /// The cells that can move to `end`, for searching backwards
+    fn find_valid_moves_to(&self, rules: &ClimbingRules, end: usize) -> Vec<usize> {
+        self.find_moves(end)
+            .into_iter()
+            .filter(|m| self.is_valid_move(rules, *m, end))
             .collect()
     }
 
-    fn has_valid_neighbor(&self, idx: usize) -> bool {
-        !self.find_valid_moves(idx).is_empty()
+    /// Whether there's any move out of the cell, or it's a dead end
+    fn has_valid_neighbor(&self, rules: &ClimbingRules, start: usize) -> bool {
+        self.find_moves(start)
+            .into_iter()
+            .any(|m| self.is_valid_move(rules, start, m))
     }
 }
 
-impl fmt::Debug for Grid<Cell> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for r in 0usize..self.num_rows() {
-            let range = self.row_first_idx(r)..=self.row_last_idx(r);
-            let line: String = self.vec[range]
-                .iter()
-                .map(|n| (n.get_height() + b'a') as char)
-                .collect();
+impl HeightMap for Grid<Cell> {
+    fn from_file_str(file_str: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(file_str, |c, coord| {
+            let kind = match c {
+                'S' => CellType::Start,
+                'E' => CellType::End,
+                'a'..='z' => CellType::Waypoint(c as u8 - b'a'),
+                _ => return Err(format!("Invalid character: {c:?}")),
+            };
 
-            writeln!(f, "{}", line)?;
-        }
+            Ok(Cell::new(kind, coord))
+        })?;
 
-        Ok(())
+        Ok(grid)
     }
 }
 
 // ----------------------------------------------------------------------------
 
-#[derive(Debug)]
+/// A path through the heightmap, from the first cell to the last. There is
+/// always at least one cell, and each is next to the one before.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path {
+    cells: Vec<usize>,
+    coords: Vec<(usize, usize)>,
+    elevations: Vec<u8>,
+}
+
+impl Path {
+    /// The indexes of the cells along the path, in order
+    pub fn cells(&self) -> &[usize] {
+        &self.cells
+    }
+
+    /// The x,y coordinates of each cell
+    pub fn coords(&self) -> &[(usize, usize)] {
+        &self.coords
+    }
+
+    /// The elevation of each cell, from 0 for `a` to 25 for `z`
+    pub fn elevations(&self) -> &[u8] {
+        &self.elevations
+    }
+
+    /// The number of moves along the path
+    pub fn steps(&self) -> usize {
+        self.cells.len() - 1
+    }
+
+    pub fn start(&self) -> usize {
+        self.cells[0]
+    }
+
+    pub fn end(&self) -> usize {
+        self.cells[self.cells.len() - 1]
+    }
+
+    /// The total height climbed along the path, not counting any descents
+    pub fn total_ascent(&self) -> usize {
+        self.elevations
+            .windows(2)
+            .map(|step| step[1].saturating_sub(step[0]) as usize)
+            .sum()
+    }
+
+    /// The direction of each move along the path
+    pub fn directions(&self) -> impl Iterator<Item = Direction> + '_ {
+        self.coords.windows(2).map(|step| {
+            let ((x1, y1), (x2, y2)) = (step[0], step[1]);
+            let delta = (x2 as isize - x1 as isize, y2 as isize - y1 as isize);
+
+            Direction::CARDINAL
+                .into_iter()
+                .find(|dir| (dir.delta().x, dir.delta().y) == delta)
+                .expect("Paths only move between neighboring cells")
+        })
+    }
+}
+
+/// The fewest moves from each cell to the end, found by searching backwards
+/// from the end. Cells the search didn't reach have no distance.
+#[derive(Debug, Clone)]
+pub struct DistanceMap<'a> {
+    finder: &'a Pathfinder,
+    pub steps: Vec<Option<usize>>,
+    /// The cell to move to next, to get closer to the end
+    next: Vec<Option<usize>>,
+}
+
+impl DistanceMap<'_> {
+    /// The shortest path from `idx` to the end
+    pub fn path_from(&self, idx: usize) -> Option<Path> {
+        self.steps.get(idx).copied().flatten()?;
+
+        let mut cells = vec![idx];
+        while let Some(next) = self.next[*cells.last().unwrap()] {
+            cells.push(next);
+        }
+
+        debug_assert_eq!(cells.last(), Some(&self.finder.end_idx));
+
+        Some(self.finder.trace(cells))
+    }
+}
+
+/// Which cells can be reached from the start, and how the heightmap divides up
+/// under the climbing rules, to explain why there might be no route
+#[derive(Debug, Clone)]
+pub struct Reachability {
+    /// The cheapest cost from the start to each cell, if it can be reached
+    pub distances: Grid<Option<usize>>,
+    /// The cells with no valid move out of them
+    pub dead_ends: Vec<usize>,
+    /// The strongly connected regions, where every cell can reach every other
+    pub regions: Vec<Vec<usize>>,
+    /// The highest elevation that can be reached from the start
+    pub highest: u8,
+    end_idx: usize,
+}
+
+impl Reachability {
+    pub fn is_reachable(&self, idx: usize) -> bool {
+        self.distances.vec.get(idx).copied().flatten().is_some()
+    }
+
+    /// The number of cells that can be reached from the start
+    pub fn reachable_count(&self) -> usize {
+        self.distances.vec.iter().filter(|d| d.is_some()).count()
+    }
+}
+
+impl fmt::Display for Reachability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Reachable from the start: {} of {} cells, up to elevation '{}'",
+            self.reachable_count(),
+            self.distances.len(),
+            (self.highest + b'a') as char
+        )?;
+
+        match self.distances.vec[self.end_idx] {
+            Some(cost) => writeln!(f, "The end can be reached at a cost of {cost}")?,
+            None => writeln!(f, "The end can't be reached")?,
+        }
+
+        let largest = self.regions.iter().map(Vec::len).max().unwrap_or(0);
+        writeln!(f, "Dead ends: {}", self.dead_ends.len())?;
+        writeln!(
+            f,
+            "Connected regions: {}, the largest with {largest} cells",
+            self.regions.len()
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct Pathfinder {
     start_idx: usize,
     end_idx: usize,
     grid: Grid<Cell>,
-    tree: Node,
+    rules: ClimbingRules,
 }
 
 impl Pathfinder {
-    pub fn from_file_str(file_str: &str) -> Self {
-        let mut pf = Pathfinder {
-            start_idx: 0,
-            end_idx: 0,
-            grid: Grid::from_file_str(file_str),
-            tree: Node::default(),
-        };
+    pub fn from_file_str(file_str: &str) -> Result<Self, ParseError> {
+        let grid = Grid::from_file_str(file_str)?;
+
+        let start_idx = grid
+            .find_pos(CellType::Start)
+            .ok_or_else(|| ParseError::new("No start position (S) in the heightmap"))?;
+        let end_idx = grid
+            .find_pos(CellType::End)
+            .ok_or_else(|| ParseError::new("No end position (E) in the heightmap"))?;
+
+        Ok(Pathfinder {
+            start_idx,
+            end_idx,
+            grid,
+            rules: ClimbingRules::default(),
+        })
+    }
 
-        let start = pf.grid.find_pos(CellType::Start).unwrap();
-        let end = pf.grid.find_pos(CellType::End).unwrap();
+    /// Search with different rules for climbing than the puzzle's
+    pub fn with_rules(self, rules: ClimbingRules) -> Self {
+        Pathfinder { rules, ..self }
+    }
 
-        pf.start_idx = start;
-        pf.end_idx = end;
-        pf.tree.idx = start;
+    /// The cost of a move between two cells under the climbing rules
+    fn step_cost(&self, from: usize, to: usize) -> usize {
+        let height = |idx: usize| self.grid.vec[idx].get_height();
 
-        pf
+        self.rules.cost(height(from), height(to))
     }
 
-    fn add_children(&mut self, node: &mut Node, idx: usize) {
-        let possible_moves = self.grid.find_valid_moves(idx);
+    pub fn print(&self) {
+        self.grid.print();
+    }
 
-        if possible_moves.is_empty() {
-            return;
+    /// The path through the given cells, if there are any, and each is next
+    /// to the one before
+    pub fn path(&self, cells: Vec<usize>) -> Option<Path> {
+        if cells.is_empty() || !cells.iter().all(|&idx| self.grid.in_bounds_idx(idx)) {
+            return None;
         }
 
-        for m in possible_moves {
-            if node.contains(m) {
-                continue;
-            }
+        let adjacent = cells.windows(2).all(|step| {
+            let ((x1, y1), (x2, y2)) = (self.grid.idx_xy(step[0]), self.grid.idx_xy(step[1]));
 
-            let n = node.add_child(m);
-            if m != self.end_idx {
-                self.add_children(n, m);
-            } else {
-                break;
-            }
+            x1.abs_diff(x2) + y1.abs_diff(y2) == 1
+        });
+
+        adjacent.then(|| self.trace(cells))
+    }
+
+    /// The path from the tree's root down to `node`
+    pub fn path_to_node(&self, node: &Node) -> Option<Path> {
+        self.path(node.route())
+    }
+
+    /// The path through cells from a search, which are always valid
+    fn trace(&self, cells: Vec<usize>) -> Path {
+        Path {
+            coords: cells.iter().map(|&idx| self.grid.idx_xy(idx)).collect(),
+            elevations: cells
+                .iter()
+                .map(|&idx| self.grid.vec[idx].get_height())
+                .collect(),
+            cells,
+        }
+    }
+
+    fn found_path(&self, found: Found<usize, usize>) -> Path {
+        self.trace(found.path)
+    }
+
+    /// Draw the path over the heightmap, with an arrow on each cell showing
+    /// the way it goes next, like the puzzle text. The path's own first and
+    /// last cells are marked `S` and `E`, so a hike from any of the lowest
+    /// cells starts where it's drawn, and `heatmap` colours every cell by its
+    /// elevation.
+    pub fn render_path(&self, path: &Path, heatmap: bool) -> String {
+        let arrows = path
+            .cells
+            .iter()
+            .zip(path.directions())
+            .map(|(&idx, dir)| (idx, dir.arrow()));
+
+        let renderer = self
+            .grid
+            .render_heights()
+            .overlay(arrows)
+            .overlay([(path.start(), 'S'), (path.end(), 'E')]);
+
+        if heatmap {
+            renderer.color(|cell| Some(cell.heat_color())).to_string()
+        } else {
+            renderer.to_string()
         }
     }
 
-    fn build_tree(&mut self) {
-        let mut tree = { self.tree.clone() };
-        let idx = { self.start_idx };
+    pub fn print_path(&self, path: &Path, heatmap: bool) {
+        print!("{}", self.render_path(path, heatmap));
+    }
+
+    /// The fewest moves from the start to the end
+    pub fn find_shortest_path(&self) -> Option<Path> {
+        self.bfs()
+    }
 
-        self.add_children(&mut tree, idx);
-        self.tree = tree;
+    /// Breadth-first search, which finds the shortest route as every move costs the same
+    pub fn bfs(&self) -> Option<Path> {
+        self.bfs_from([self.start_idx])
     }
 
-    fn get_paths(&self) -> impl Iterator<Item = &Node> {
-        self.tree
-            .get_leaves()
+    /// Breadth-first search from several starting cells at once, finding the
+    /// shortest route to the end from whichever of them is closest
+    pub fn bfs_from(&self, starts: impl IntoIterator<Item = usize>) -> Option<Path> {
+        search::bfs_from(self, starts, |&idx| idx == self.end_idx)
+            .map(|found| self.found_path(found))
+    }
+
+    /// Breadth-first search from the start and the end at once
+    pub fn bidirectional_bfs(&self) -> Option<Path> {
+        search::bidirectional_bfs(self, self.start_idx, self.end_idx)
+            .map(|found| self.found_path(found))
+    }
+
+    /// The fewest moves to the end from the nearest lowest cell, found by
+    /// searching backwards from the end until reaching any lowest cell
+    pub fn find_shortest_hike(&self) -> Option<Path> {
+        let is_lowest = |&idx: &usize| self.grid.vec[idx].get_height() == 0;
+        let mut found = search::bfs(&search::Reversed(self), self.end_idx, is_lowest)?;
+        found.path.reverse();
+
+        Some(self.trace(found.path))
+    }
+
+    /// The fewest moves to the end from every cell, found by following the
+    /// moves backwards from the end
+    pub fn distances_to_end(&self) -> DistanceMap<'_> {
+        let reached = search::bfs_distances(&search::Reversed(self), self.end_idx);
+
+        // Searching backwards, each cell was reached from the one after it
+        let (steps, next) = (0..self.grid.len())
+            .map(|idx| match reached.get(&idx) {
+                Some(&(steps, next)) => (Some(steps), next),
+                None => (None, None),
+            })
+            .unzip();
+
+        DistanceMap {
+            finder: self,
+            steps,
+            next,
+        }
+    }
+
+    /// Which cells can be reached from the start, with the dead ends and
+    /// connected regions of the whole heightmap
+    pub fn reachability(&self) -> Reachability {
+        let reached = search::distances(self, self.start_idx);
+
+        let mut distances = Grid::new(self.grid.num_cols());
+        distances.vec = (0..self.grid.len())
+            .map(|idx| reached.get(&idx).copied())
+            .collect();
+
+        let dead_ends = (0..self.grid.len())
+            .filter(|&idx| !self.grid.has_valid_neighbor(&self.rules, idx))
+            .collect();
+
+        let highest = reached
+            .keys()
+            .map(|&idx| self.grid.vec[idx].get_height())
+            .max()
+            .unwrap_or(0);
+
+        Reachability {
+            distances,
+            dead_ends,
+            regions: search::strongly_connected(self, 0..self.grid.len()),
+            highest,
+            end_idx: self.end_idx,
+        }
+    }
+
+    /// The cheapest path from the start to the end, and its total cost
+    pub fn find_cheapest_path(&self) -> Option<(Path, usize)> {
+        search::dijkstra(self, self.start_idx, |&idx| idx == self.end_idx)
+            .map(|found| (self.trace(found.path), found.cost))
+    }
+
+    /// A* search, guided by the Manhattan distance to the end, which never
+    /// overestimates the cost left as long as every move costs at least one
+    pub fn a_star(&self) -> Option<Path> {
+        let end = self.grid.idx_xy(self.end_idx);
+        let heuristic = |&idx: &usize| {
+            let (x, y) = self.grid.idx_xy(idx);
+
+            x.abs_diff(end.0) + y.abs_diff(end.1)
+        };
+
+        search::a_star(self, self.start_idx, |&idx| idx == self.end_idx, heuristic)
+            .map(|found| self.found_path(found))
+    }
+}
+
+/// Each cell is a state, and the climbing rules decide the moves and their costs
+impl Graph for Pathfinder {
+    type State = usize;
+    type Cost = usize;
+
+    fn neighbors(&self, idx: &usize) -> impl Iterator<Item = (usize, usize)> {
+        self.grid
+            .find_valid_moves(&self.rules, *idx)
             .into_iter()
-            .filter(|n| n.idx == self.end_idx)
+            .map(|next| (next, self.step_cost(*idx, next)))
     }
+}
 
-    pub fn find_shortest_path(&mut self) -> &Node {
-        self.build_tree();
+impl ReversibleGraph for Pathfinder {
+    fn predecessors(&self, idx: &usize) -> impl Iterator<Item = (usize, usize)> {
+        self.grid
+            .find_valid_moves_to(&self.rules, *idx)
+            .into_iter()
+            .map(|prev| (prev, self.step_cost(prev, *idx)))
+    }
+}
 
-        self.get_paths()
-            .min_by(|a, b| a.get_len().cmp(&b.get_len()))
-            .unwrap()
+// ----------------------------------------------------------------------------
+
+/// The answer to either part: the fewest steps to the end, or why it
+/// can't be reached
+#[derive(Debug, Clone)]
+pub enum Steps {
+    Fewest(usize),
+    /// No route from the start, with what can be reached from it
+    NoRoute(Box<Reachability>),
+    /// No route from any of the lowest cells, with how many cells can reach the end
+    NoHike {
+        lowest: usize,
+        reaching_end: usize,
+        cells: usize,
+    },
+}
+
+impl fmt::Display for Steps {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Steps::Fewest(steps) => write!(f, "{steps}"),
+            Steps::NoRoute(reachability) => write!(
+                f,
+                "No route: {} of {} cells reachable from the start",
+                reachability.reachable_count(),
+                reachability.distances.len()
+            ),
+            Steps::NoHike {
+                lowest,
+                reaching_end,
+                cells,
+            } => write!(
+                f,
+                "No route from the {lowest} lowest cells: \
+                 {reaching_end} of {cells} cells can reach the end"
+            ),
+        }
+    }
+}
+
+/// The heightmap, and the start and end of the route
+pub struct Day12 {
+    finder: Pathfinder,
+}
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Hill Climbing Algorithm";
+
+    type Part1 = Steps;
+    type Part2 = Steps;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day12 {
+            finder: Pathfinder::from_file_str(input)?,
+        })
+    }
+
+    /// Fewest steps from the start to the end
+    fn part1(&self) -> Steps {
+        match self.finder.find_shortest_path() {
+            Some(path) => Steps::Fewest(path.steps()),
+            None => Steps::NoRoute(Box::new(self.finder.reachability())),
+        }
+    }
+
+    /// Fewest steps to the end from any of the lowest cells
+    fn part2(&self) -> Steps {
+        if let Some(path) = self.finder.find_shortest_hike() {
+            return Steps::Fewest(path.steps());
+        }
+
+        let grid = &self.finder.grid;
+        Steps::NoHike {
+            lowest: grid
+                .vec
+                .iter()
+                .filter(|cell| cell.get_height() == 0)
+                .count(),
+            reaching_end: self
+                .finder
+                .distances_to_end()
+                .steps
+                .iter()
+                .flatten()
+                .count(),
+            cells: grid.len(),
+        }
     }
 }
 
@@ -267,21 +635,272 @@ mod tests {
     }
 
     fn get_finder() -> Pathfinder {
-        Pathfinder::from_file_str(get_test_data())
+        Pathfinder::from_file_str(get_test_data()).unwrap()
     }
 
     #[test]
     fn find_valid_moves() {
         let finder = get_finder();
 
-        assert_eq!(finder.grid.find_valid_moves(finder.start_idx), vec![8, 1]);
-        assert_eq!(finder.grid.find_valid_moves(8), vec![0, 16, 9]);
+        let rules = ClimbingRules::default();
+
+        assert_eq!(
+            finder.grid.find_valid_moves(&rules, finder.start_idx),
+            vec![8, 1]
+        );
+        assert_eq!(finder.grid.find_valid_moves(&rules, 8), vec![0, 16, 9]);
     }
 
     #[test]
     fn find_shortest_path() {
-        let mut finder = get_finder();
-        let shortest = finder.find_shortest_path();
-        assert_eq!(shortest.get_len(), 31);
+        let finder = get_finder();
+        let shortest = finder.find_shortest_path().unwrap();
+        assert_eq!(shortest.steps(), 31);
+    }
+
+    #[test]
+    fn path() {
+        let finder = get_finder();
+        let path = finder.path(vec![0, 1, 9, 10, 2, 3]).unwrap();
+
+        assert_eq!(path.steps(), 5);
+        assert_eq!(
+            path.coords,
+            vec![(0, 0), (1, 0), (1, 1), (2, 1), (2, 0), (3, 0)]
+        );
+        assert_eq!(path.elevations, vec![0, 0, 1, 2, 1, 16]);
+        assert_eq!(path.total_ascent(), 17);
+        assert_eq!(
+            path.directions().collect::<Vec<_>>(),
+            vec![
+                Direction::Right,
+                Direction::Down,
+                Direction::Right,
+                Direction::Up,
+                Direction::Right
+            ]
+        );
+
+        let shortest = finder.find_shortest_path().unwrap();
+        assert_eq!(shortest.directions().count(), shortest.steps());
+        assert_eq!(shortest.total_ascent(), 25);
+    }
+
+    #[test]
+    fn invalid_paths() {
+        let finder = get_finder();
+
+        assert_eq!(finder.path(vec![]), None);
+        assert_eq!(finder.path(vec![0, 2]), None);
+        assert_eq!(finder.path(vec![7, 8]), None);
+        assert_eq!(finder.path(vec![39, 40]), None);
+        assert_eq!(finder.path(vec![5]).unwrap().steps(), 0);
+    }
+
+    #[test]
+    fn node_conversion() {
+        let finder = get_finder();
+        let path = finder.find_shortest_path().unwrap();
+
+        let tree = Node::from(&path);
+        let leaves = tree.get_leaves();
+
+        assert_eq!(tree.idx, finder.start_idx);
+        assert_eq!(leaves.len(), 1);
+        assert_eq!(leaves[0].idx, finder.end_idx);
+        assert_eq!(leaves[0].get_len(), path.steps());
+        assert!(leaves[0].contains(finder.start_idx));
+        assert_eq!(finder.path_to_node(leaves[0]), Some(path));
+    }
+
+    #[test]
+    fn search_algorithms_agree() {
+        let finder = get_finder();
+
+        for path in [
+            finder.bfs(),
+            finder.bidirectional_bfs(),
+            finder.find_cheapest_path().map(|(path, _)| path),
+            finder.a_star(),
+        ] {
+            let path = path.unwrap();
+
+            assert_eq!(path.steps(), 31);
+            assert_eq!(path.start(), finder.start_idx);
+            assert_eq!(path.end(), finder.end_idx);
+
+            for step in path.cells.windows(2) {
+                assert!(finder.grid.is_valid_move(&finder.rules, step[0], step[1]));
+            }
+        }
+    }
+
+    #[test]
+    fn climbing_rules() {
+        let rules = ClimbingRules::default();
+        assert!(rules.allows(3, 4));
+        assert!(!rules.allows(3, 5));
+        assert!(rules.allows(25, 0));
+
+        let rules = ClimbingRules {
+            max_ascent: 0,
+            max_descent: 1,
+            ..rules
+        };
+        assert!(rules.allows(3, 2));
+        assert!(!rules.allows(3, 1));
+        assert!(!rules.allows(3, 4));
+    }
+
+    #[test]
+    fn find_cheapest_path() {
+        let finder = get_finder();
+
+        // With the puzzle's rules, the cheapest route is the shortest one
+        let (path, cost) = finder.find_cheapest_path().unwrap();
+        assert_eq!((path.steps(), cost), (31, 31));
+
+        // With a rope to climb anything, the end is only 7 moves away
+        let rope = |cheap: bool| ClimbingRules {
+            max_ascent: 25,
+            step_cost: if cheap {
+                |_, _| 1
+            } else {
+                |from, to| if to > from + 1 { 100 } else { 1 }
+            },
+            ..ClimbingRules::default()
+        };
+        let (path, cost) = finder
+            .clone()
+            .with_rules(rope(true))
+            .find_cheapest_path()
+            .unwrap();
+        assert_eq!((path.steps(), cost), (7, 7));
+
+        // But if the rope is expensive, it's cheaper to walk around
+        let (path, cost) = finder
+            .clone()
+            .with_rules(rope(false))
+            .find_cheapest_path()
+            .unwrap();
+        assert_eq!((path.steps(), cost), (31, 31));
+
+        // Without the rope or any way down, there's no path at all
+        let flat = ClimbingRules {
+            max_ascent: 0,
+            max_descent: 0,
+            ..ClimbingRules::default()
+        };
+        assert_eq!(finder.with_rules(flat).find_cheapest_path(), None);
+    }
+
+    #[test]
+    fn render_path() {
+        let finder = get_finder();
+        let path = finder.find_shortest_path().unwrap();
+
+        // The same path as drawn in the puzzle text
+        assert_eq!(
+            finder.render_path(&path, false),
+            "Sabv<<<<\n>vcvv<<^\navcv>E^^\na>v>>>^^\nab>>>>>^\n"
+        );
+
+        let heatmap = finder.render_path(&path, true);
+        let heatmap: Vec<&str> = heatmap.lines().collect();
+        assert!(heatmap[0].starts_with("\x1b[38;2;0;64;255mS\x1b[0m"));
+        assert!(heatmap[4].ends_with("\x1b[38;2;81;64;174m^\x1b[0m"));
+
+        // A hike is marked from its own start, not the puzzle's
+        let hike = finder.find_shortest_hike().unwrap();
+        assert_eq!(
+            finder.render_path(&hike, false),
+            "aabv<<<<\nabcvv<<^\naccv>E^^\na>v>>>^^\nS^>>>>>^\n"
+        );
+    }
+
+    #[test]
+    fn reachability() {
+        let finder = get_finder();
+        let reachability = finder.reachability();
+
+        assert_eq!(reachability.reachable_count(), 40);
+        assert_eq!(reachability.distances.vec[finder.start_idx], Some(0));
+        assert_eq!(reachability.distances.vec[finder.end_idx], Some(31));
+        assert!(reachability.dead_ends.is_empty());
+
+        // The start is walled in, and the end is only reachable from the high ground
+        let finder = Pathfinder::from_file_str("Sz\nzE").unwrap();
+        let reachability = finder.reachability();
+
+        assert_eq!(reachability.distances.vec, vec![Some(0), None, None, None]);
+        assert!(!reachability.is_reachable(finder.end_idx));
+        assert_eq!(reachability.dead_ends, vec![finder.start_idx]);
+        assert_eq!(reachability.regions.len(), 2);
+        assert_eq!(
+            reachability.to_string(),
+            [
+                "Reachable from the start: 1 of 4 cells, up to elevation 'a'",
+                "The end can't be reached",
+                "Dead ends: 1",
+                "Connected regions: 2, the largest with 3 cells",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn find_shortest_hike() {
+        let finder = get_finder();
+        let hike = finder.find_shortest_hike().unwrap();
+
+        assert_eq!(hike.steps(), 29);
+        assert_eq!(hike.elevations[0], 0);
+        assert_eq!(hike.end(), finder.end_idx);
+
+        let lowest: Vec<usize> = finder
+            .grid
+            .cells()
+            .filter(|(_, cell)| cell.get_height() == 0)
+            .map(|(idx, _)| idx)
+            .collect();
+        assert_eq!(finder.bfs_from(lowest).unwrap().steps(), 29);
+    }
+
+    #[test]
+    fn distances_to_end() {
+        let finder = get_finder();
+        let distances = finder.distances_to_end();
+
+        assert_eq!(distances.steps[finder.end_idx], Some(0));
+        assert_eq!(distances.steps[finder.start_idx], Some(31));
+        assert_eq!(distances.path_from(finder.start_idx).unwrap().steps(), 31);
+    }
+
+    #[test]
+    fn test_answers() {
+        let day = Day12::parse(get_test_data()).unwrap();
+        assert_eq!(day.part1().to_string(), "31");
+        assert_eq!(day.part2().to_string(), "29");
+
+        // The start is walled in, so neither part has a route
+        let day = Day12::parse("Sz\nzE").unwrap();
+        assert_eq!(
+            day.part1().to_string(),
+            "No route: 1 of 4 cells reachable from the start"
+        );
+        assert_eq!(
+            day.part2().to_string(),
+            "No route from the 1 lowest cells: 3 of 4 cells can reach the end"
+        );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let err = Day12::parse("Sab\nc#E").err().unwrap();
+        assert_eq!(err.to_string(), "Line 2, column 2: Invalid character: '#'");
+
+        let err = Day12::parse("Sab\ncde").err().unwrap();
+        assert_eq!(err.to_string(), "No end position (E) in the heightmap");
     }
 }
@@ -1,7 +1,8 @@
+use aoc_shared::solution::run;
+use day12::Day12;
+
 fn main() {
-    let file_str = include_str!("input.txt");
-    let mut finder = ::day12::Pathfinder::from_file_str(file_str);
-    let shortest_path = finder.find_shortest_path();
+    let file_str = aoc_shared::read_input!();
 
-    println!("Part 1: Fewest steps: {}", shortest_path.get_len());
+    run::<Day12>(&file_str);
 }
@@ -1,3 +1,5 @@
+use crate::Path;
+
 #[derive(Debug, Default, Clone)]
 pub struct Node {
     pub idx: usize,
@@ -61,4 +63,34 @@ impl Node {
     pub fn get_len(&self) -> usize {
         self.parents.len()
     }
+
+    /// The cells from the root of the tree down to this node
+    pub fn route(&self) -> Vec<usize> {
+        let mut route = self.parents.clone();
+        route.push(self.idx);
+
+        route
+    }
+}
+
+/// A tree with a single branch, following the path from its start. Only the
+/// leaf holds the route in `parents`, so building it takes linear time.
+impl From<&Path> for Node {
+    fn from(path: &Path) -> Self {
+        let (&end, route) = path
+            .cells()
+            .split_last()
+            .expect("Paths always have at least one cell");
+        let leaf = Node {
+            idx: end,
+            parents: route.to_vec(),
+            children: Vec::new(),
+        };
+
+        route.iter().rev().fold(leaf, |child, &idx| Node {
+            idx,
+            parents: Vec::new(),
+            children: vec![child],
+        })
+    }
 }
@@ -0,0 +1,209 @@
+use aoc_shared::parse::{parse_lines, ParseError};
+use aoc_shared::solution::Solution;
+use std::str::FromStr;
+
+#[derive(Debug, Copy, Clone)]
+enum Calculation {
+    ByMove,
+    ByOutcome,
+}
+use Calculation::*;
+
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Copy, Clone)]
+enum Outcome {
+    Win,
+    Draw,
+    Lose,
+}
+use Outcome::*;
+
+impl TryFrom<char> for Outcome {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'X' => Ok(Lose),
+            'Y' => Ok(Draw),
+            'Z' => Ok(Win),
+            _ => Err(ParseError::invalid("outcome", &c.to_string())),
+        }
+    }
+}
+
+impl Outcome {
+    fn get_score(self) -> u32 {
+        match self {
+            Lose => 0,
+            Draw => 3,
+            Win => 6,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Copy, Clone)]
+enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+use Shape::*;
+
+impl TryFrom<char> for Shape {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'A' | 'X' => Ok(Rock),
+            'B' | 'Y' => Ok(Paper),
+            'C' | 'Z' => Ok(Scissors),
+            _ => Err(ParseError::invalid("shape", &c.to_string())),
+        }
+    }
+}
+
+impl Shape {
+    fn get_score(self) -> u32 {
+        match self {
+            Rock => 1,
+            Paper => 2,
+            Scissors => 3,
+        }
+    }
+
+    fn get_outcome(self, outcome: Outcome) -> Shape {
+        match self {
+            Rock => match outcome {
+                Win => Paper,
+                Draw => Rock,
+                Lose => Scissors,
+            },
+            Paper => match outcome {
+                Win => Scissors,
+                Draw => Paper,
+                Lose => Rock,
+            },
+            Scissors => match outcome {
+                Win => Rock,
+                Draw => Scissors,
+                Lose => Paper,
+            },
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+fn get_outcome_score(them: Shape, you: Shape) -> u32 {
+    match (them, you) {
+        (Rock, Scissors) | (Paper, Rock) | (Scissors, Paper) => Lose,
+
+        (Rock, Rock) | (Paper, Paper) | (Scissors, Scissors) => Draw,
+
+        (Rock, Paper) | (Paper, Scissors) | (Scissors, Rock) => Win,
+    }
+    .get_score()
+}
+
+/// A round of the strategy guide. The second column is either your shape,
+/// or the outcome of the round, depending on how the guide is read.
+#[derive(Debug, Copy, Clone)]
+struct Round {
+    them: Shape,
+    you: Shape,
+    outcome: Outcome,
+}
+
+impl FromStr for Round {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars: Vec<char> = s.chars().collect();
+
+        let [them, ' ', you] = chars[..] else {
+            return Err(ParseError::invalid("round", s));
+        };
+
+        Ok(Round {
+            them: Shape::try_from(them).map_err(|e| e.column(1))?,
+            you: Shape::try_from(you).map_err(|e| e.column(3))?,
+            outcome: Outcome::try_from(you).map_err(|e| e.column(3))?,
+        })
+    }
+}
+
+fn get_round_score(round: &Round, score_type: Calculation) -> u32 {
+    let them = round.them;
+    let you = match score_type {
+        ByMove => round.you,
+        ByOutcome => them.get_outcome(round.outcome),
+    };
+
+    let shape_score = you.get_score();
+    let outcome_score = get_outcome_score(them, you);
+
+    shape_score + outcome_score
+}
+
+fn get_total(rounds: &[Round], score_type: Calculation) -> u32 {
+    rounds
+        .iter()
+        .map(|round| get_round_score(round, score_type))
+        .sum()
+}
+
+// ----------------------------------------------------------------------------
+
+/// The strategy guide
+pub struct Day2 {
+    rounds: Vec<Round>,
+}
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Rock Paper Scissors";
+
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let rounds = parse_lines(input)?;
+
+        Ok(Day2 { rounds })
+    }
+
+    /// Final score, treating the second column as your move
+    fn part1(&self) -> u32 {
+        get_total(&self.rounds, ByMove)
+    }
+
+    /// Final score, treating the second column as the outcome
+    fn part2(&self) -> u32 {
+        get_total(&self.rounds, ByOutcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let day = Day2::parse(include_str!("test-input.txt")).unwrap();
+
+        assert_eq!(day.part1(), 15);
+        assert_eq!(day.part2(), 12);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let err = "A Y\nB Q\n".parse::<Round>().unwrap_err();
+        assert_eq!(err.to_string(), "Invalid round \"A Y\\nB Q\\n\"");
+
+        let err = Day2::parse("A Y\nB Q\n").err().unwrap();
+        assert_eq!(err.to_string(), "Line 2, column 3: Invalid shape \"Q\"");
+    }
+}
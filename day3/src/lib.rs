@@ -0,0 +1,204 @@
+use aoc_shared::parse::ParseError;
+use aoc_shared::solution::Solution;
+
+fn get_split_rucksack_items(list: &str) -> (Vec<char>, Vec<char>) {
+    let all_chars: Vec<_> = list.chars().collect();
+
+    let half_index = all_chars.len() / 2;
+    let halves = all_chars.split_at(half_index);
+
+    assert_eq!(halves.0.len(), halves.1.len());
+
+    (halves.0.to_vec(), halves.1.to_vec())
+}
+
+fn get_rucksack_items(list: &str) -> Vec<char> {
+    list.chars().collect()
+}
+
+fn get_priority(item: char) -> u32 {
+    let is_uppercase = item.is_ascii_uppercase();
+    let char = if is_uppercase {
+        item.to_ascii_lowercase()
+    } else {
+        item
+    };
+
+    let priority = match char {
+        'a' => 1,
+        'b' => 2,
+        'c' => 3,
+        'd' => 4,
+        'e' => 5,
+        'f' => 6,
+        'g' => 7,
+        'h' => 8,
+        'i' => 9,
+        'j' => 10,
+        'k' => 11,
+        'l' => 12,
+        'm' => 13,
+        'n' => 14,
+        'o' => 15,
+        'p' => 16,
+        'q' => 17,
+        'r' => 18,
+        's' => 19,
+        't' => 20,
+        'u' => 21,
+        'v' => 22,
+        'w' => 23,
+        'x' => 24,
+        'y' => 25,
+        'z' => 26,
+        _ => 0,
+    };
+
+    if is_uppercase {
+        priority + 26
+    } else {
+        priority
+    }
+}
+
+fn find_same_item(a: Vec<char>, b: Vec<char>) -> Option<char> {
+    a.into_iter().find(|ch| b.contains(ch))
+}
+
+fn find_same_item_in_three(a: &[char], b: &[char], c: &[char]) -> Option<char> {
+    a.iter()
+        .copied()
+        .find(|ch| b.contains(ch) && c.contains(ch))
+}
+
+/// Make sure each item is a letter, both compartments are the same size,
+/// and they have an item in common
+fn check_rucksack(list: &str) -> Result<String, ParseError> {
+    if let Some(col) = list.chars().position(|ch| !ch.is_ascii_alphabetic()) {
+        return Err(ParseError::invalid("item", list).column(col + 1));
+    }
+
+    if !list.len().is_multiple_of(2) {
+        return Err(ParseError::new("Odd number of items").context(list));
+    }
+
+    let (a, b) = get_split_rucksack_items(list);
+    if find_same_item(a, b).is_none() {
+        return Err(ParseError::new("No item in both compartments").context(list));
+    }
+
+    Ok(list.to_string())
+}
+
+/// Make sure the rucksacks split into groups of three, each with a badge in common
+fn check_groups(lines: &[String]) -> Result<(), ParseError> {
+    for (i, group) in lines.chunks(3).enumerate() {
+        let line = i * 3 + 1;
+
+        let [a, b, c] = group else {
+            return Err(ParseError::new("Group of fewer than three rucksacks").line(line));
+        };
+
+        let (a, b, c) = (
+            get_rucksack_items(a),
+            get_rucksack_items(b),
+            get_rucksack_items(c),
+        );
+        if find_same_item_in_three(&a, &b, &c).is_none() {
+            return Err(ParseError::new("No badge common to the group").line(line));
+        }
+    }
+
+    Ok(())
+}
+
+// ----------------------------------------------------------------------------
+
+/// The items in each rucksack, one rucksack per line
+pub struct Day3 {
+    lines: Vec<String>,
+}
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Rucksack Reorganization";
+
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let lines: Vec<String> = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| check_rucksack(line).map_err(|e| e.line(i + 1)))
+            .collect::<Result<_, _>>()?;
+        check_groups(&lines)?;
+
+        Ok(Day3 { lines })
+    }
+
+    /// Priority sum of the item in both compartments of each rucksack
+    fn part1(&self) -> u32 {
+        self.lines
+            .iter()
+            .map(|line| get_split_rucksack_items(line))
+            .map(|(a, b)| find_same_item(a, b).expect("Checked when parsing"))
+            .map(get_priority)
+            .sum()
+    }
+
+    /// Priority sum of the badge of each group of three elves
+    fn part2(&self) -> u32 {
+        let sacks: Vec<Vec<char>> = self
+            .lines
+            .iter()
+            .map(|line| get_rucksack_items(line))
+            .collect();
+
+        sacks
+            .chunks(3)
+            .map(|chunk| {
+                find_same_item_in_three(&chunk[0], &chunk[1], &chunk[2])
+                    .expect("Checked when parsing")
+            })
+            .map(get_priority)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let day = Day3::parse(include_str!("test-input.txt")).unwrap();
+
+        assert_eq!(day.part1(), 157);
+        assert_eq!(day.part2(), 70);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let err = Day3::parse("abAa\nab-B").err().unwrap();
+        assert_eq!(err.to_string(), "Line 2, column 3: Invalid item \"ab-B\"");
+
+        let err = Day3::parse("abA").err().unwrap();
+        assert_eq!(err.to_string(), "Line 1: Odd number of items \"abA\"");
+
+        let err = Day3::parse("abcd\nabcd\nabcd\nabcd").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Line 1: No item in both compartments \"abcd\""
+        );
+
+        let err = Day3::parse("abca\nabca\nabca\nabca").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Line 4: Group of fewer than three rucksacks"
+        );
+
+        let err = Day3::parse("abca\nabca\nxyzx").err().unwrap();
+        assert_eq!(err.to_string(), "Line 1: No badge common to the group");
+    }
+}
@@ -0,0 +1,132 @@
+use aoc_shared::parse::{parse_value, ParseError};
+use aoc_shared::solution::Solution;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, PartialOrd)]
+struct Range {
+    low: u32,
+    high: u32,
+}
+
+impl FromStr for Range {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (low, high) = s
+            .split_once('-')
+            .ok_or_else(|| ParseError::invalid("range", s))?;
+
+        let low: u32 = parse_value("section", low)?;
+        let high: u32 =
+            parse_value("section", high).map_err(|e| e.column(s.len() - high.len() + 1))?;
+
+        Ok(Range { low, high })
+    }
+}
+
+impl Range {
+    fn contains(&self, other: &Range) -> bool {
+        self.low <= other.low && self.high >= other.high
+            || other.low <= self.low && other.high >= self.high
+    }
+
+    fn overlap(&self, other: &Range) -> bool {
+        let range_a = self.low..=self.high;
+        let range_b = other.low..=other.high;
+
+        range_a.contains(&other.low)
+            || range_a.contains(&other.high)
+            || range_b.contains(&self.low)
+            || range_b.contains(&self.high)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+fn parse_ranges(line: &str) -> Result<(Range, Range), ParseError> {
+    let (a, b) = line
+        .split_once(',')
+        .ok_or_else(|| ParseError::invalid("pair of ranges", line))?;
+
+    let range_a = a.parse().map_err(|e: ParseError| e.column(1))?;
+    let range_b = b
+        .parse()
+        .map_err(|e: ParseError| offset_column(e, a.len() + 1))?;
+
+    Ok((range_a, range_b))
+}
+
+/// Adjust the column of an error from parsing part of a line
+fn offset_column(e: ParseError, offset: usize) -> ParseError {
+    let column = e.column.unwrap_or(1) + offset;
+
+    ParseError {
+        column: Some(column),
+        ..e
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// The pairs of section assignments, one pair per line
+pub struct Day4 {
+    pairs: Vec<(Range, Range)>,
+}
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Camp Cleanup";
+
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let pairs = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| parse_ranges(line).map_err(|e| e.line(i + 1)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Day4 { pairs })
+    }
+
+    /// Pairs where one range fully contains the other
+    fn part1(&self) -> usize {
+        self.pairs
+            .iter()
+            .filter(|(range_a, range_b)| range_a.contains(range_b))
+            .count()
+    }
+
+    /// Pairs where the ranges overlap
+    fn part2(&self) -> usize {
+        self.pairs
+            .iter()
+            .filter(|(range_a, range_b)| range_a.overlap(range_b))
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let day = Day4::parse(include_str!("test-input.txt")).unwrap();
+
+        assert_eq!(day.part1(), 2);
+        assert_eq!(day.part2(), 4);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert_eq!("12-345".parse(), Ok(Range { low: 12, high: 345 }));
+
+        let err = Day4::parse("2-4,6-8\n2-3,4-x").err().unwrap();
+        assert_eq!(err.to_string(), "Line 2, column 7: Invalid section \"x\"");
+
+        let err = Day4::parse("2-4,6-8\n2-3").err().unwrap();
+        assert_eq!(err.to_string(), "Line 2: Invalid pair of ranges \"2-3\"");
+    }
+}
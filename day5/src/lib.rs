@@ -0,0 +1,180 @@
+use aoc_shared::parse::{parse_lines, parse_value, ParseError};
+use aoc_shared::solution::Solution;
+use std::str::FromStr;
+
+struct Move {
+    items: usize,
+    from: usize,
+    to: usize,
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split_ascii_whitespace().collect();
+
+        let ["move", items, "from", from, "to", to] = parts[..] else {
+            return Err(ParseError::invalid("move", s));
+        };
+
+        Ok(Move {
+            items: parse_value("crate count", items)?,
+            from: parse_value("stack number", from)?,
+            to: parse_value("stack number", to)?,
+        })
+    }
+}
+
+impl Move {
+    fn apply(&self, layout: &mut [Vec<char>]) {
+        for _ in 0..self.items {
+            if let Some(item) = layout[self.from].pop() {
+                layout[self.to].push(item);
+            }
+        }
+    }
+
+    fn apply_multiple(&self, layout: &mut [Vec<char>]) {
+        let item_count = self.items;
+        let max = layout[self.from].len();
+        let min = max.saturating_sub(item_count);
+
+        let from = layout.get_mut(self.from).unwrap();
+
+        // Clone this so we can borrow immutably
+        let from2 = from.clone();
+        let (rem, to_move) = from2.split_at(min);
+
+        from.clear();
+        from.append(&mut rem.to_vec());
+
+        layout[self.to].append(&mut to_move.to_vec());
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Parse the drawing of the stacks of crates. Stacks are numbered from 1,
+/// so the first stack is left empty.
+fn get_initial_layout(drawing: &str) -> Vec<Vec<char>> {
+    let mut rows: Vec<&str> = drawing.lines().collect();
+    let stack_count = rows
+        .pop()
+        .map_or(0, |labels| labels.split_ascii_whitespace().count());
+
+    let mut layout = vec![Vec::new(); stack_count + 1];
+
+    for row in rows.iter().rev() {
+        let row: Vec<char> = row.chars().collect();
+
+        for (i, stack) in layout.iter_mut().enumerate().skip(1) {
+            match row.get(4 * i - 3) {
+                Some(ch) if ch.is_ascii_alphabetic() => stack.push(*ch),
+                _ => (),
+            }
+        }
+    }
+
+    layout
+}
+
+fn get_position_string(layout: &[Vec<char>]) -> String {
+    let mut s = String::new();
+
+    for stack in layout.iter().skip(1) {
+        if let Some(ch) = stack.last() {
+            s.push(*ch);
+        }
+    }
+
+    s
+}
+
+// ----------------------------------------------------------------------------
+
+/// The starting stacks of crates, and the moves to make
+pub struct Day5 {
+    layout: Vec<Vec<char>>,
+    moves: Vec<Move>,
+}
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Supply Stacks";
+
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (drawing, moves) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::new("Missing blank line after the stacks"))?;
+        let first_move_line = drawing.lines().count() + 2;
+
+        let layout = get_initial_layout(drawing);
+        let moves: Vec<Move> = parse_lines(moves).map_err(|e| e.offset_line(first_move_line))?;
+
+        for (i, m) in moves.iter().enumerate() {
+            if [m.from, m.to]
+                .iter()
+                .any(|&stack| stack == 0 || stack >= layout.len())
+            {
+                return Err(ParseError::new("No such stack")
+                    .line(first_move_line + i)
+                    .context(format!("move {} from {} to {}", m.items, m.from, m.to)));
+            }
+        }
+
+        Ok(Day5 { layout, moves })
+    }
+
+    /// Top crates after moving crates one at a time
+    fn part1(&self) -> String {
+        let mut layout = self.layout.clone();
+        self.moves.iter().for_each(|m| m.apply(&mut layout));
+
+        get_position_string(&layout)
+    }
+
+    /// Top crates after moving crates in bulk
+    fn part2(&self) -> String {
+        let mut layout = self.layout.clone();
+        self.moves
+            .iter()
+            .for_each(|m| m.apply_multiple(&mut layout));
+
+        get_position_string(&layout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let day = Day5::parse(include_str!("test-input.txt")).unwrap();
+
+        assert_eq!(day.part1(), "CMZ");
+        assert_eq!(day.part2(), "MCD");
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let drawing = "[A]    \n[B] [C]\n 1   2 \n\n";
+
+        let err = Day5::parse(&format!("{drawing}move 1 from 1 to 2\nmove x from 1 to 2"))
+            .err()
+            .unwrap();
+        assert_eq!(err.to_string(), "Line 6: Invalid crate count \"x\"");
+
+        let err = Day5::parse(&format!("{drawing}move 1 from 1 to 3"))
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Line 5: No such stack \"move 1 from 1 to 3\""
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use aoc_shared::parse::ParseError;
+use aoc_shared::solution::Solution;
+
+/// The number of characters up to the end of the first run of `marker_size`
+/// different characters, if there is one
+fn find_num_chars_before_marker(chars: &[char], marker_size: usize) -> Option<usize> {
+    chars
+        .windows(marker_size)
+        .position(|window| {
+            // Sort the window, so any duplicate characters end up side by side
+            let mut cursor = window.to_vec();
+            cursor.sort_unstable();
+
+            cursor.windows(2).all(|pair| pair[0] != pair[1])
+        })
+        // The marker ends marker_size characters after the start of its window
+        .map(|i| i + marker_size)
+}
+
+fn find_packet_marker(chars: &[char]) -> Option<usize> {
+    find_num_chars_before_marker(chars, 4)
+}
+
+fn find_message_marker(chars: &[char]) -> Option<usize> {
+    find_num_chars_before_marker(chars, 14)
+}
+
+// ----------------------------------------------------------------------------
+
+/// The datastream buffer
+pub struct Day6 {
+    chars: Vec<char>,
+}
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Tuning Trouble";
+
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let chars: Vec<char> = input.trim_end().chars().collect();
+
+        // A start-of-message marker also contains a start-of-packet marker
+        if find_message_marker(&chars).is_none() {
+            return Err(ParseError::new(
+                "No start-of-message marker in the datastream",
+            ));
+        }
+
+        Ok(Day6 { chars })
+    }
+
+    /// Number of characters before the start-of-packet marker
+    fn part1(&self) -> usize {
+        find_packet_marker(&self.chars).expect("Checked when parsing")
+    }
+
+    /// Number of characters before the start-of-message marker
+    fn part2(&self) -> usize {
+        find_message_marker(&self.chars).expect("Checked when parsing")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let day = Day6::parse(include_str!("test-input.txt")).unwrap();
+
+        assert_eq!(day.part1(), 7);
+        assert_eq!(day.part2(), 19);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        for input in ["aaaa", "abc", "abcdefghijklm", ""] {
+            let err = Day6::parse(input).err().unwrap();
+            assert_eq!(
+                err.to_string(),
+                "No start-of-message marker in the datastream"
+            );
+        }
+    }
+}
@@ -0,0 +1,333 @@
+#![allow(dead_code)]
+
+use aoc_shared::parse::{parse_lines, parse_value, ParseError};
+use aoc_shared::solution::Solution;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+const MAX_DIR_SIZE: u128 = 100_000;
+const TOTAL_DISK_SPACE: u128 = 70_000_000;
+const MIN_SPACE_REQUIRED: u128 = 30_000_000;
+
+#[derive(Debug)]
+struct File {
+    name: String,
+    size: u128,
+}
+
+impl File {
+    fn new(size: u128, name: &str) -> Self {
+        let name = name.to_string();
+
+        File { name, size }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct Dir {
+    parent: String,
+    name: String,
+    files: Vec<File>,
+    subdirs: Vec<String>,
+}
+
+impl Dir {
+    fn new<T: ToString + ?Sized>(parent: &T, name: &T) -> Self {
+        Dir {
+            parent: parent.to_string(),
+            name: name.to_string(),
+            files: Vec::new(),
+            subdirs: Vec::new(),
+        }
+    }
+
+    fn add_file(&mut self, file: File) {
+        self.files.push(file);
+    }
+
+    fn add_subdir(&mut self, path: String) {
+        self.subdirs.push(path);
+    }
+
+    fn get_loose_files_size(&self) -> u128 {
+        self.files
+            .iter()
+            .map(|file| file.size)
+            .reduce(|accum, item| accum + item)
+            .unwrap_or(0)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Debug)]
+enum LineType {
+    Cd(String),
+    Ls,
+    Dir(String),
+    FileAndSize(u128, String),
+}
+
+use LineType::*;
+
+impl FromStr for LineType {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = line.split_ascii_whitespace().collect();
+
+        match parts[..] {
+            ["$", "cd", dir] => Ok(Cd(dir.to_string())),
+            ["$", "ls"] => Ok(Ls),
+            ["$", ..] => Err(ParseError::invalid("command", line)),
+            ["dir", name] => Ok(Dir(name.to_string())),
+            [size, name] => Ok(FileAndSize(
+                parse_value("file size", size)?,
+                name.to_string(),
+            )),
+            _ => Err(ParseError::invalid("terminal output", line)),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct DirMap {
+    current_path: String,
+    map: HashMap<String, Dir>,
+}
+
+impl DirMap {
+    fn new() -> Self {
+        let current_path = "/".to_string();
+        let mut map: HashMap<String, Dir> = HashMap::new();
+        map.insert(current_path.clone(), Dir::new("", ""));
+
+        DirMap { current_path, map }
+    }
+
+    /// Change to a directory that has already been listed
+    fn cd<T: ToString + ?Sized>(&mut self, new_dir: &T) -> Result<(), ParseError> {
+        let mut path = self.current_path.clone();
+        let new = new_dir.to_string();
+
+        match new.as_str() {
+            "/" => {
+                path = new.to_string();
+            }
+            ".." => {
+                let mut dir_parts: Vec<&str> = path.split('/').collect();
+                let _ = dir_parts.pop();
+                path = dir_parts.join("/");
+            }
+            _ => {
+                path.push('/');
+                path.push_str(&new);
+            }
+        }
+
+        if !self.map.contains_key(&path) {
+            return Err(ParseError::new("Unknown directory").context(new));
+        }
+
+        self.current_path = path;
+
+        Ok(())
+    }
+
+    /// The directory at the current path, which `cd` made sure exists
+    fn current_dir(&mut self) -> &mut Dir {
+        self.map
+            .get_mut(&self.current_path)
+            .expect("The current dir should already exist")
+    }
+
+    fn dir<T: ToString + ?Sized>(&mut self, dir: &T) {
+        let parent = self.current_path.clone();
+        let name = dir.to_string();
+
+        let mut full_path = parent.clone();
+        full_path.push('/');
+        full_path.push_str(&name);
+
+        // Add the new Dir to the path map
+        if !self.map.contains_key(&full_path) {
+            self.map.insert(full_path.clone(), Dir::new(&parent, &name));
+        }
+
+        // Add the new Dir to the list of subdirectories to the Dir mapped to the current path
+        self.current_dir().add_subdir(full_path.clone());
+    }
+
+    fn parse(&mut self, item: LineType) -> Result<(), ParseError> {
+        match item {
+            Cd(s) => self.cd(&s)?,
+            Ls => {}
+            Dir(s) => self.dir(&s),
+            FileAndSize(size, name) => self.current_dir().add_file(File::new(size, &name)),
+        }
+
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+fn get_path_size_map(dir_map: &DirMap) -> HashMap<String, u128> {
+    let mut size_map: HashMap<String, u128> = HashMap::new();
+
+    // Get the sizes of the leaf node directories
+    dir_map
+        .map
+        .iter()
+        .filter(|(_, v)| v.subdirs.is_empty())
+        .for_each(|(k, v)| {
+            size_map.insert(k.to_string(), v.get_loose_files_size());
+        });
+
+    // Calculate dir sizes by the length of the path from largest to smallest,
+    // so we can start with the lowest branches of the tree when calculating folder sizes
+    let mut branch_paths: Vec<&String> = dir_map
+        .map
+        .iter()
+        .filter(|(_, v)| !v.subdirs.is_empty())
+        .map(|(k, _)| k)
+        .collect();
+    branch_paths.sort();
+    branch_paths.reverse();
+
+    branch_paths.into_iter().for_each(|path| {
+        let dir = dir_map.map.get(path).unwrap();
+
+        let base_size = dir.get_loose_files_size();
+        let subdir_size: u128 = dir
+            .subdirs
+            .iter()
+            .map(|sub| {
+                *size_map
+                    .get(sub)
+                    .expect("Dir {} should already have had its size calculated")
+            })
+            .reduce(|accum, item| accum + item)
+            .unwrap_or(0);
+
+        size_map.insert(path.to_string(), base_size + subdir_size);
+    });
+
+    size_map
+}
+
+/// How much more space has to be freed, which is none if there's already enough
+fn calculate_needed_space(used_space: u128) -> u128 {
+    MIN_SPACE_REQUIRED.saturating_sub(TOTAL_DISK_SPACE.saturating_sub(used_space))
+}
+
+fn find_size_of_dir(min_size: u128, size_map: &HashMap<String, u128>) -> u128 {
+    size_map
+        .values()
+        .copied()
+        .filter(|v| *v >= min_size)
+        .min()
+        .expect("The root dir is at least as big as the space needed")
+}
+
+fn calculate_sum_of_dirs(size_map: &HashMap<String, u128>) -> u128 {
+    size_map
+        .iter()
+        .filter(|(_, v)| **v < MAX_DIR_SIZE)
+        .fold(0u128, |acc, (_, v)| acc + *v)
+}
+
+// ----------------------------------------------------------------------------
+
+/// The total size of each directory, by path
+pub struct Day7 {
+    size_map: HashMap<String, u128>,
+}
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "No Space Left On Device";
+
+    type Part1 = u128;
+    type Part2 = u128;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut path_map = DirMap::new();
+
+        for (i, cmd) in parse_lines::<LineType>(input)?.into_iter().enumerate() {
+            path_map.parse(cmd).map_err(|e| e.line(i + 1))?;
+        }
+
+        Ok(Day7 {
+            size_map: get_path_size_map(&path_map),
+        })
+    }
+
+    /// Sum of the directories of 100K or smaller
+    fn part1(&self) -> u128 {
+        calculate_sum_of_dirs(&self.size_map)
+    }
+
+    /// Size of the smallest directory to delete
+    fn part2(&self) -> u128 {
+        let used_space = *self.size_map.get("/").unwrap();
+
+        find_size_of_dir(calculate_needed_space(used_space), &self.size_map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_size_of_dir() {
+        let mut size_map: HashMap<String, u128> = HashMap::new();
+        size_map.insert("//e".to_string(), 584);
+        size_map.insert("//a".to_string(), 94853);
+        size_map.insert("//d".to_string(), 24933642);
+        size_map.insert("/".to_string(), 48381165);
+
+        let res = find_size_of_dir(8381165, &size_map);
+
+        assert_eq!(res, 24933642);
+    }
+
+    #[test]
+    fn test_calculate_needed_space() {
+        let res = calculate_needed_space(48381165);
+
+        assert_eq!(res, 8381165);
+        assert_eq!(calculate_needed_space(1000), 0);
+    }
+
+    #[test]
+    fn test_example() {
+        let day = Day7::parse(include_str!("test-input.txt")).unwrap();
+
+        assert_eq!(day.part1(), 95437);
+        assert_eq!(day.part2(), 24933642);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let err = Day7::parse("$ cd /\n$ rm -rf a").err().unwrap();
+        assert_eq!(err.to_string(), "Line 2: Invalid command \"$ rm -rf a\"");
+
+        let err = Day7::parse("$ ls\n12k b.txt").err().unwrap();
+        assert_eq!(err.to_string(), "Line 2: Invalid file size \"12k\"");
+
+        let err = Day7::parse("$ cd a\n$ ls\n12 b.txt").err().unwrap();
+        assert_eq!(err.to_string(), "Line 1: Unknown directory \"a\"");
+
+        let err = Day7::parse("$ cd /\n$ cd ..").err().unwrap();
+        assert_eq!(err.to_string(), "Line 2: Unknown directory \"..\"");
+
+        let day = Day7::parse("$ cd /\n$ ls\n12 b.txt").unwrap();
+        assert_eq!(day.part2(), 12);
+    }
+}
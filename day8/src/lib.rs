@@ -0,0 +1,334 @@
+use aoc_shared::enums::Direction;
+use aoc_shared::grid::Grid;
+use aoc_shared::grid_like::GridLike;
+use aoc_shared::parse::ParseError;
+use aoc_shared::solution::Solution;
+use std::collections::HashSet;
+
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Eq, Hash)]
+enum VisibleDirection {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+use VisibleDirection::*;
+
+impl VisibleDirection {
+    /// The direction to look in, from a tree towards this side of the grid
+    fn outward(self) -> Direction {
+        match self {
+            Top => Direction::Up,
+            Bottom => Direction::Down,
+            Left => Direction::Left,
+            Right => Direction::Right,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Tree {
+    height: usize,
+    visible: HashSet<VisibleDirection>,
+}
+
+impl Tree {
+    fn new(height: usize) -> Self {
+        Tree {
+            height,
+            ..Tree::default()
+        }
+    }
+
+    fn is_visible(&self) -> bool {
+        !self.visible.is_empty()
+    }
+
+    fn set_visible(&mut self, dir: VisibleDirection) -> &mut Self {
+        self.visible.insert(dir);
+
+        self
+    }
+
+    fn set_all_visible(&mut self) -> &mut Self {
+        self.set_visible(Top)
+            .set_visible(Bottom)
+            .set_visible(Left)
+            .set_visible(Right)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+// The tree-specific behaviour of the grid, added through an extension trait
+// (so methods can be implemented on the library type directly).
+trait Forest: GridLike<Cell = Tree> {
+    fn from_file_str(file_str: &str) -> Result<Self, ParseError>
+    where
+        Self: Sized;
+
+    fn mark_outer_trees_visible(&mut self) -> &mut Self {
+        fn set_row_visible(row: Option<&mut [Tree]>) {
+            row.into_iter().flatten().for_each(|tree| {
+                tree.set_all_visible();
+            })
+        }
+
+        // Set top/bottom rows as visible
+        set_row_visible(self.get_row(0));
+        let last_row = self.num_rows().saturating_sub(1);
+        set_row_visible(self.get_row(last_row));
+
+        // Set left/right cols as visible
+        self.get_column_indexes(0)
+            .into_iter()
+            .flatten()
+            .for_each(|id| {
+                self.as_grid_mut().vec[id].set_all_visible();
+            });
+        self.get_column_indexes(self.num_cols().saturating_sub(1))
+            .into_iter()
+            .flatten()
+            .for_each(|id| {
+                self.as_grid_mut().vec[id].set_all_visible();
+            });
+
+        self
+    }
+
+    fn mark_visible(&mut self, dir: VisibleDirection) -> &mut Self {
+        // Orient the grid so that looking from `dir` means scanning each row left to right
+        let view = self.view();
+        let view = match dir {
+            Left => view,
+            Right => view.flip_horizontal(),
+            Top => view.transpose(),
+            Bottom => view.transpose().flip_horizontal(),
+        };
+
+        let mut visible = Vec::new();
+
+        // Skip first and last rows, as those are already marked visible
+        for y in 1..(view.height() - 1) {
+            let mut tallest = 0usize;
+
+            for (idx, tree) in view.row(y) {
+                if tallest < tree.height {
+                    visible.push(idx);
+
+                    tallest = tree.height;
+                }
+            }
+        }
+
+        for idx in visible {
+            self.as_grid_mut().vec[idx].set_visible(dir);
+        }
+
+        self
+    }
+
+    fn mark_visible_trees(&mut self) {
+        self.mark_outer_trees_visible()
+            .mark_visible(Top)
+            .mark_visible(Right)
+            .mark_visible(Bottom)
+            .mark_visible(Left);
+    }
+
+    fn get_visible_trees(&self) -> usize {
+        self.cells().filter(|(_, tree)| tree.is_visible()).count()
+    }
+
+    fn get_viewing_distances(&self, reference: usize) -> [usize; 4] {
+        let ref_tree_height = self.get(reference).unwrap().height;
+
+        [Top, Right, Bottom, Left].map(|dir| {
+            let mut count = 0usize;
+
+            for (_, tree) in self.ray(reference, dir.outward()) {
+                count += 1;
+
+                if ref_tree_height <= tree.height {
+                    break;
+                }
+            }
+
+            count
+        })
+    }
+
+    fn get_scenic_score(&self, reference: usize) -> usize {
+        let [t, r, b, l] = self.get_viewing_distances(reference);
+
+        t * r * b * l
+    }
+
+    fn get_max_scenic_score(&self) -> usize {
+        self.cells()
+            .map(|(idx, _)| idx)
+            .map(|idx| self.get_scenic_score(idx))
+            .max()
+            .unwrap()
+    }
+}
+
+impl Forest for Grid<Tree> {
+    fn from_file_str(file_str: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(file_str, |ch, _| {
+            ch.to_digit(10)
+                .map(|height| Tree::new(height as usize))
+                .ok_or(format!("Invalid tree height: {ch:?}"))
+        })?;
+
+        Ok(grid)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// The tree heights, with the visible trees already marked
+pub struct Day8 {
+    grid: Grid<Tree>,
+}
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Treetop Tree House";
+
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut grid = Grid::from_file_str(input)?;
+        grid.mark_visible_trees();
+
+        Ok(Day8 { grid })
+    }
+
+    /// Number of trees visible from outside the grid
+    fn part1(&self) -> usize {
+        self.grid.get_visible_trees()
+    }
+
+    /// Highest scenic score of any tree
+    fn part2(&self) -> usize {
+        self.grid.get_max_scenic_score()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_data() -> &'static str {
+        include_str!("test-input.txt")
+    }
+
+    #[test]
+    fn test_row_first_index() {
+        let grid = Grid::from_file_str(get_data()).unwrap();
+
+        assert_eq!(grid.row_first_idx(1), Some(5));
+        assert_eq!(grid.row_first_idx(0), Some(0));
+        assert_eq!(grid.row_first_idx(2), Some(10));
+        assert_eq!(grid.row_first_idx(5), None);
+    }
+
+    #[test]
+    fn test_row_last_index() {
+        let grid = Grid::from_file_str(get_data()).unwrap();
+
+        assert_eq!(grid.row_last_idx(0), Some(4));
+        assert_eq!(grid.row_last_idx(1), Some(9));
+        assert_eq!(grid.row_last_idx(5), None);
+    }
+
+    #[test]
+    fn test_get_column_indexes() {
+        let grid = Grid::from_file_str(get_data()).unwrap();
+
+        assert_eq!(grid.num_cols(), 5);
+
+        assert_eq!(grid.get_column_indexes(0), Some(vec![0, 5, 10, 15, 20]));
+        assert_eq!(grid.get_column_indexes(1), Some(vec![1, 6, 11, 16, 21]));
+        assert_eq!(grid.get_column_indexes(4), Some(vec![4, 9, 14, 19, 24]));
+        assert_eq!(grid.get_column_indexes(5), None);
+    }
+
+    #[test]
+    fn test_outer_visible_trees() {
+        let mut grid = Grid::from_file_str(get_data()).unwrap();
+        grid.mark_outer_trees_visible();
+
+        assert_eq!(grid.get_visible_trees(), 16usize);
+    }
+
+    #[test]
+    fn test_visible_trees() {
+        let mut grid = Grid::from_file_str(get_data()).unwrap();
+        grid.mark_visible_trees();
+
+        let visible = [(1usize, 1usize), (2, 1), (1, 2), (4, 3), (2, 3)];
+
+        for (x, y) in visible {
+            let idx = grid.xy_idx(x, y);
+
+            assert!(
+                grid.vec[idx].is_visible(),
+                "Tree {}({},{}) should be visible: {:#?}",
+                idx,
+                x,
+                y,
+                grid.vec[idx]
+            );
+        }
+
+        assert_eq!(grid.get_visible_trees(), 21usize);
+    }
+
+    #[test]
+    fn test_get_surrounding_trees() {
+        let grid = Grid::from_file_str(get_data()).unwrap();
+        let ray = |dir| grid.ray(7, dir).map(|(idx, _)| idx).collect::<Vec<usize>>();
+
+        assert_eq!(ray(Top.outward()), vec![2]);
+        assert_eq!(ray(Right.outward()), vec![8, 9]);
+        assert_eq!(ray(Bottom.outward()), vec![12, 17, 22]);
+        assert_eq!(ray(Left.outward()), vec![6, 5]);
+    }
+
+    #[test]
+    fn test_get_viewing_distances() {
+        let grid = Grid::from_file_str(get_data()).unwrap();
+
+        assert_eq!(grid.get_viewing_distances(7), [1, 2, 2, 1]);
+        assert_eq!(grid.get_viewing_distances(17), [2, 2, 1, 2]);
+    }
+
+    #[test]
+    fn test_get_scenic_score() {
+        let grid = Grid::from_file_str(get_data()).unwrap();
+
+        assert_eq!(grid.get_scenic_score(7), 4);
+        assert_eq!(grid.get_scenic_score(17), 8);
+
+        assert_eq!(grid.get_max_scenic_score(), 8);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let err = Day8::parse("303\n2x5\n").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Line 2, column 2: Invalid tree height: 'x'"
+        );
+
+        let err = Day8::parse("303\n25\n").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Line 2, column 3: Expected 3 columns, found 2"
+        );
+    }
+}
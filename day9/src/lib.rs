@@ -0,0 +1,216 @@
+use aoc_shared::parse::{parse_lines, parse_value, ParseError};
+use aoc_shared::solution::Solution;
+use aoc_shared::{Direction, Location, SparseGrid};
+use std::str::FromStr;
+
+#[derive(Debug, Copy, Clone)]
+struct Move {
+    dir: Direction,
+    amount: isize,
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (dir, amount) = line
+            .split_once(' ')
+            .ok_or_else(|| ParseError::invalid("move", line))?;
+
+        let amount_column = dir.len() + 2;
+
+        let dir = dir.parse().map_err(|e| ParseError::from(e).column(1))?;
+        let amount = parse_value("step count", amount).map_err(|e| e.column(amount_column))?;
+
+        Ok(Move { dir, amount })
+    }
+}
+
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Default)]
+struct Rope {
+    knots: Vec<Location>,
+    knot_count: usize,
+    head_visited: SparseGrid<usize>,
+    tail_visited: SparseGrid<usize>,
+}
+
+impl Rope {
+    pub fn new(knot_count: usize) -> Self {
+        let mut rope = Rope {
+            knot_count,
+            ..Self::default()
+        };
+        rope.head_visited.insert(Location::default(), 1);
+        rope.tail_visited.insert(Location::default(), 1);
+        rope.knots.resize_with(knot_count, Location::default);
+
+        rope
+    }
+
+    pub fn get_knot(&self, idx: usize) -> Location {
+        self.knots[idx]
+    }
+
+    pub fn is_tail(&self, idx: usize) -> bool {
+        idx == (self.knot_count - 1)
+    }
+
+    pub fn move_head(&mut self, moves: Move) {
+        for _ in 0..moves.amount {
+            let to = self.knots[0] + moves.dir;
+            self.knots[0] = to;
+            *self.head_visited.get_or_insert_with(to, || 0) += 1;
+
+            for i in 1..self.knot_count {
+                self.move_knot(i, i - 1);
+            }
+        }
+    }
+
+    fn must_move(&mut self, current: usize, prev: usize) -> bool {
+        // Knots that are touching, even diagonally, stay put
+        self.get_knot(current)
+            .chebyshev_distance(self.get_knot(prev))
+            > 1
+    }
+
+    fn move_knot(&mut self, c: usize, p: usize) {
+        if !self.must_move(c, p) {
+            return;
+        }
+
+        let current = self.get_knot(c).step_toward(self.get_knot(p));
+
+        self.knots[c] = current;
+        if self.is_tail(c) {
+            *self.tail_visited.get_or_insert_with(current, || 0) += 1;
+        }
+    }
+
+    fn get_tail_pos_count(&self) -> usize {
+        self.tail_visited.len()
+    }
+
+    /// Draw the area the head has covered, marking the positions the tail has
+    /// visited with `#`, and the start with `s`
+    #[cfg(test)]
+    fn render_tail_visited(&self) -> String {
+        self.head_visited
+            .render(|_| '.')
+            .highlight(self.tail_visited.locations(), '#')
+            .highlight([Location::default()], 's')
+            .to_string()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// The moves of the head of the rope
+pub struct Day9 {
+    moves: Vec<Move>,
+}
+
+impl Day9 {
+    fn get_tail_pos_count(&self, knot_count: usize) -> usize {
+        let mut rope = Rope::new(knot_count);
+        self.moves.iter().for_each(|m| rope.move_head(*m));
+
+        rope.get_tail_pos_count()
+    }
+}
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Rope Bridge";
+
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let moves = parse_lines(input)?;
+
+        Ok(Day9 { moves })
+    }
+
+    /// Number of positions visited by the tail, with 2 knots
+    fn part1(&self) -> usize {
+        self.get_tail_pos_count(2)
+    }
+
+    /// Number of positions visited by the tail, with 10 knots
+    fn part2(&self) -> usize {
+        self.get_tail_pos_count(10)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_location_get_distance() {
+        let a = Location::new(0, 0);
+
+        assert_eq!(a.get_distance(Location::new(0, 0)), 0.0);
+        assert_eq!(a.get_distance(Location::new(1, 0)), 1.0);
+        assert_eq!(a.get_distance(Location::new(1, 1)), 2.0f64.sqrt());
+    }
+
+    #[test]
+    fn test_must_move() {
+        let mut rope = Rope::new(2);
+
+        rope.knots[0] = Location::new(1, 1);
+        assert!(!rope.must_move(1, 0));
+
+        rope.knots[0] = Location::new(2, 1);
+        assert!(rope.must_move(1, 0));
+
+        rope.move_knot(1, 0);
+        assert_eq!(rope.get_knot(1), Location::new(1, 1));
+    }
+
+    #[test]
+    fn test_get_tail_position_count() {
+        let file_str = include_str!("test-input.txt");
+        let mut rope = Rope::new(2);
+
+        parse_lines(file_str)
+            .unwrap()
+            .into_iter()
+            .for_each(|m| rope.move_head(m));
+
+        assert_eq!(rope.get_tail_pos_count(), 13);
+        assert_eq!(
+            rope.render_tail_visited(),
+            "..##..\n...##.\n.####.\n....#.\ns###..\n"
+        );
+    }
+
+    #[test]
+    fn test_get_tail_position_count_10_knots() {
+        let file_str = include_str!("test-input2.txt");
+        let mut rope = Rope::new(10);
+
+        parse_lines(file_str)
+            .unwrap()
+            .into_iter()
+            .for_each(|m| rope.move_head(m));
+
+        assert_eq!(rope.get_tail_pos_count(), 36);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let err = Day9::parse("R 4\nX 4").err().unwrap();
+        assert_eq!(err.to_string(), "Line 2, column 1: Invalid direction \"X\"");
+
+        let err = Day9::parse("R 4\nU -").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Line 2, column 3: Invalid step count \"-\""
+        );
+    }
+}
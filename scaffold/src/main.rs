@@ -0,0 +1,316 @@
+//! Generates the crate for a new day, and registers it with the workspace and the `aoc` runner
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const USAGE: &str = "Usage: scaffold <day> [--title <title>]";
+
+const CARGO_TOML: &str = r#"[package]
+name = "day{day}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc-shared = { path = "../aoc-shared"}
+"#;
+
+const README: &str = "# Day {day}: {title}
+
+## Part 1
+
+";
+
+const MAIN_RS: &str = "use aoc_shared::solution::run;
+use day{day}::Day{day};
+
+fn main() {
+    let file_str = aoc_shared::read_input!();
+
+    run::<Day{day}>(&file_str);
+}
+";
+
+const LIB_RS: &str = r#"use aoc_shared::parse::ParseError;
+use aoc_shared::solution::Solution;
+
+// ----------------------------------------------------------------------------
+
+/// The parsed puzzle input
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    const DAY: u8 = {day};
+    const TITLE: &'static str = "{title}";
+
+    type Part1 = &'static str;
+    type Part2 = &'static str;
+
+    fn parse(_input: &str) -> Result<Self, ParseError> {
+        Ok(Day{day})
+    }
+
+    fn part1(&self) -> &'static str {
+        "Not solved yet"
+    }
+
+    fn part2(&self) -> &'static str {
+        "Not solved yet"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> &'static str {
+        include_str!("test-input.txt")
+    }
+
+    #[test]
+    fn test_example() {
+        let day = Day{day}::parse(get_test_data()).unwrap();
+
+        assert_eq!(day.part1(), "Not solved yet");
+        assert_eq!(day.part2(), "Not solved yet");
+    }
+}
+"#;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Args {
+    day: u8,
+    title: String,
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+    let mut args = args.into_iter();
+
+    let day = match args.next() {
+        Some(day) => match day.parse::<u8>() {
+            Ok(day @ 1..=25) => day,
+            _ => return Err(format!("Invalid day: {day}")),
+        },
+        None => return Err("Missing day".to_string()),
+    };
+
+    let mut title = "TODO".to_string();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--title" => match args.next() {
+                Some(t) if !t.contains(['"', '\\']) => title = t,
+                Some(t) => return Err(format!("Invalid title: {t}")),
+                None => return Err("Missing value for --title".to_string()),
+            },
+            _ => return Err(format!("Unknown argument: {arg}")),
+        }
+    }
+
+    Ok(Args { day, title })
+}
+
+fn fill(template: &str, args: &Args) -> String {
+    template
+        .replace("{day}", &args.day.to_string())
+        .replace("{title}", &args.title)
+}
+
+/// The day number at the start of a line, after `prefix` and any indentation
+fn line_day(line: &str, prefix: &str) -> Option<u8> {
+    let rest = line.trim_start().strip_prefix(prefix)?;
+    let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+
+    digits.parse().ok()
+}
+
+/// Insert `new_line` among the lines starting with `prefix` and a day number,
+/// keeping them in order of day
+fn insert_day_line(text: &str, prefix: &str, day: u8, new_line: &str) -> Result<String, String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let days: Vec<Option<u8>> = lines.iter().map(|line| line_day(line, prefix)).collect();
+
+    if days.contains(&Some(day)) {
+        return Err(format!("Day {day} is already registered"));
+    }
+
+    let idx = match days.iter().rposition(|d| d.is_some_and(|d| d < day)) {
+        Some(idx) => idx + 1,
+        None => days
+            .iter()
+            .position(Option::is_some)
+            .ok_or_else(|| format!("No lines starting with {prefix:?} to add day {day} to"))?,
+    };
+
+    lines.insert(idx, new_line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// A file to write, with its new contents
+struct Change {
+    path: PathBuf,
+    contents: String,
+}
+
+/// Work out every file to create or update, before writing any of them
+fn plan(root: &Path, args: &Args) -> Result<Vec<Change>, String> {
+    let day = args.day;
+    let day_dir = root.join(format!("day{day}"));
+
+    if day_dir.exists() {
+        return Err(format!("{} already exists", day_dir.display()));
+    }
+
+    let read =
+        |path: &Path| fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()));
+    let update = |path: PathBuf, prefix: &str, new_line: String| -> Result<Change, String> {
+        let contents = insert_day_line(&read(&path)?, prefix, day, &new_line)
+            .map_err(|e| format!("{}: {e}", path.display()))?;
+
+        Ok(Change { path, contents })
+    };
+    let create = |path: &str, template: &str| Change {
+        path: day_dir.join(path),
+        contents: fill(template, args),
+    };
+
+    Ok(vec![
+        create("Cargo.toml", CARGO_TOML),
+        create("README.md", README),
+        create("src/main.rs", MAIN_RS),
+        create("src/lib.rs", LIB_RS),
+        create("src/input.txt", ""),
+        create("src/test-input.txt", ""),
+        update(
+            root.join("Cargo.toml"),
+            "\"day",
+            format!("    \"day{day}\","),
+        )?,
+        update(
+            root.join("aoc/Cargo.toml"),
+            "day",
+            format!("day{day} = {{ path = \"../day{day}\"}}"),
+        )?,
+        update(
+            root.join("aoc/src/main.rs"),
+            "Solver::of::<day",
+            format!("    Solver::of::<day{day}::Day{day}>(),"),
+        )?,
+    ])
+}
+
+fn main() {
+    let args = parse_args(std::env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        eprintln!("{USAGE}");
+        std::process::exit(2);
+    });
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+
+    let changes = plan(root, &args).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        std::process::exit(1);
+    });
+
+    for change in changes {
+        let result = change
+            .path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| fs::write(&change.path, &change.contents));
+
+        match result {
+            Ok(()) => println!("Wrote {}", change.path.display()),
+            Err(e) => {
+                eprintln!("{}: {e}", change.path.display());
+                std::process::exit(1);
+            }
+        }
+    }
+
+    println!(
+        "Paste the puzzle input into day{}/src/input.txt, and the example into src/test-input.txt",
+        args.day
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &str) -> Result<Args, String> {
+        parse_args(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            args("13"),
+            Ok(Args {
+                day: 13,
+                title: "TODO".to_string()
+            })
+        );
+        assert_eq!(args("13 --title Signal").unwrap().title, "Signal");
+
+        assert!(args("").is_err());
+        assert!(args("0").is_err());
+        assert!(args("26").is_err());
+        assert!(args("13 --title").is_err());
+        assert!(args("13 --verbose").is_err());
+    }
+
+    #[test]
+    fn test_insert_day_line() {
+        let members = "members = [\n    \"aoc\",\n    \"day1\",\n    \"day2\",\n]\n";
+
+        assert_eq!(
+            insert_day_line(members, "\"day", 3, "    \"day3\","),
+            Ok(
+                "members = [\n    \"aoc\",\n    \"day1\",\n    \"day2\",\n    \"day3\",\n]\n"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            insert_day_line(members, "\"day", 10, "    \"day10\",")
+                .map(|s| s.contains("\"day2\",\n    \"day10\"")),
+            Ok(true)
+        );
+        assert!(insert_day_line(members, "\"day", 2, "    \"day2\",").is_err());
+        assert!(insert_day_line("[dependencies]\n", "day", 2, "day2 = {}").is_err());
+
+        let solvers = "&[\n    Solver::of::<day2::Day2>(),\n    Solver::of::<day12::Day12>(),\n];";
+        assert_eq!(
+            insert_day_line(solvers, "Solver::of::<day", 1, "    Solver::of::<day1::Day1>(),"),
+            Ok("&[\n    Solver::of::<day1::Day1>(),\n    Solver::of::<day2::Day2>(),\n    Solver::of::<day12::Day12>(),\n];\n".to_string())
+        );
+    }
+
+    #[test]
+    fn test_plan() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        let args = Args {
+            day: 25,
+            title: "Full of Hot Air".to_string(),
+        };
+
+        let changes = plan(root, &args).unwrap();
+        let contents = |path: &str| {
+            changes
+                .iter()
+                .find(|change| change.path == root.join(path))
+                .map(|change| change.contents.as_str())
+                .unwrap()
+        };
+
+        assert!(contents("day25/src/lib.rs")
+            .contains("const TITLE: &'static str = \"Full of Hot Air\";"));
+        assert!(contents("Cargo.toml").contains("    \"day25\",\n]"));
+        assert!(contents("aoc/Cargo.toml").ends_with("day25 = { path = \"../day25\"}\n"));
+        assert!(contents("aoc/src/main.rs").contains("    Solver::of::<day25::Day25>(),\n];"));
+
+        assert!(plan(root, &Args { day: 1, ..args }).is_err());
+    }
+}